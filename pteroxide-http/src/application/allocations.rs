@@ -1,37 +1,63 @@
-use pteroxide_models::{application::Allocation, fractal::FractalList};
+use pteroxide_models::application::Allocation;
 use serde::Serialize;
 
-use crate::{error::*, routing::Application as Route, Application, Builder};
+use crate::{
+    error::*,
+    query::AllocationFilter,
+    response::{impl_paginate, Response},
+    routing::Application as Route,
+    Application, Builder,
+};
 
 #[derive(Debug)]
 pub struct GetAllocations<'a> {
     app: &'a Application,
     node: i32,
+    filters: Vec<AllocationFilter<'a>>,
+    page: u32,
+    per_page: u32,
 }
 
 impl<'a> GetAllocations<'a> {
     #[doc(hidden)]
     pub const fn new(app: &'a Application, node: i32) -> Self {
-        Self { app, node }
+        Self {
+            app,
+            node,
+            filters: Vec::new(),
+            page: 1,
+            per_page: 50,
+        }
     }
 
-    /// Asynchronously executes the request and returns a list of [`Allocation`] objects.
-    ///
-    /// ## Errors
-    ///
-    /// Returns an [`Error`] if the request fails.
-    pub async fn exec(&self) -> Result<Vec<Allocation>, Error> {
-        let res = self
-            .app
-            .request::<FractalList<Allocation>>(Builder::new(
-                Route::GetAllocations { node: self.node }.into(),
-            ))
-            .await?;
+    /// Adds an [`AllocationFilter`] to filter the allocations by.
+    pub fn filter(mut self, filter: AllocationFilter<'a>) -> Self {
+        self.filters.push(filter);
 
-        Ok(res.data.iter().map(|a| a.attributes.clone()).collect())
+        self
+    }
+
+    /// Returns a paginated [`Response`] for the request, which can fetch individual pages, collect
+    /// all pages or lazily walk through them as a stream.
+    pub fn paginate(&self) -> Response<'a, Allocation> {
+        let mut res = Response::new(
+            &self.app.http,
+            Route::GetAllocations { node: self.node }.into(),
+        )
+        .page(self.page)
+        .per_page(self.per_page);
+
+        for filter in &self.filters {
+            let (key, value) = filter.param();
+            res = res.param(key, &value);
+        }
+
+        res
     }
 }
 
+impl_paginate!(GetAllocations, Allocation, "allocations");
+
 #[derive(Debug, Default, Serialize)]
 struct CreateAllocationsFields<'a> {
     pub ip: &'a str,
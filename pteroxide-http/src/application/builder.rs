@@ -0,0 +1,31 @@
+use super::{Application, DEFAULT_USER_AGENT};
+use crate::{
+    http::{impl_http_builder, HttpBuilder},
+    Error,
+};
+
+/// A builder for configuring an [`Application`] interface, including the HTTP client, timeouts
+/// and headers sent with every request.
+#[derive(Debug)]
+pub struct ApplicationBuilder(HttpBuilder);
+
+impl ApplicationBuilder {
+    /// Creates a new builder with the given API credentials.
+    pub fn new(url: String, key: String) -> Self {
+        Self(HttpBuilder::new(url, key, DEFAULT_USER_AGENT))
+    }
+
+    /// Builds the [`Application`] interface.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an [`Error`] if the user agent is not a valid header value or if the HTTP client
+    /// fails to be built.
+    pub fn build(self) -> Result<Application, Error> {
+        Ok(Application {
+            http: self.0.build()?,
+        })
+    }
+}
+
+impl_http_builder!(ApplicationBuilder, "Pteroxide HTTP Application");
@@ -1,9 +1,10 @@
-use pteroxide_models::{
-    application::Egg,
-    fractal::{FractalItem, FractalList},
-};
+use pteroxide_models::{application::Egg, fractal::FractalItem};
 
-use crate::{routing::Application as Route, Application, Builder, Error};
+use crate::{
+    response::{impl_paginate, Response},
+    routing::Application as Route,
+    Application, Builder, Error,
+};
 
 #[derive(Debug)]
 pub struct GetEggs<'a> {
@@ -13,6 +14,9 @@ pub struct GetEggs<'a> {
     with_nest: bool,
     with_script: bool,
     with_servers: bool,
+    with_variables: bool,
+    page: u32,
+    per_page: u32,
 }
 
 impl<'a> GetEggs<'a> {
@@ -25,6 +29,9 @@ impl<'a> GetEggs<'a> {
             with_nest: false,
             with_script: false,
             with_servers: false,
+            with_variables: false,
+            page: 1,
+            per_page: 50,
         }
     }
 
@@ -68,33 +75,45 @@ impl<'a> GetEggs<'a> {
         self
     }
 
-    /// Asynchronously executes the request and returns a list of [`Egg`] objects.
-    ///
-    /// ## Errors
+    /// Include the [`variables`] of the egg in the egg [`relationships`].
     ///
-    /// Returns an [`Error`] if the request fails.
-    pub async fn exec(&self) -> Result<Vec<Egg>, Error> {
-        let mut builder = Builder::new(Route::GetEggs { nest: self.nest }.into());
+    /// [`variables`]: pteroxide_models::application::EggVariable
+    /// [`relationships`]: pteroxide_models::application::EggRelations
+    pub fn with_variables(mut self, value: bool) -> Self {
+        self.with_variables = value;
+
+        self
+    }
+
+    /// Returns a paginated [`Response`] for the request, which can fetch individual pages, collect
+    /// all pages or lazily walk through them as a stream.
+    pub fn paginate(&self) -> Response<'a, Egg> {
+        let mut res = Response::new(&self.app.http, Route::GetEggs { nest: self.nest }.into())
+            .page(self.page)
+            .per_page(self.per_page);
 
         if self.with_config {
-            builder = builder.include("config");
+            res = res.include("config");
         }
         if self.with_nest {
-            builder = builder.include("nest");
+            res = res.include("nest");
         }
         if self.with_script {
-            builder = builder.include("script");
+            res = res.include("script");
         }
         if self.with_servers {
-            builder = builder.include("servers");
+            res = res.include("servers");
+        }
+        if self.with_variables {
+            res = res.include("variables");
         }
 
-        let res = self.app.request::<FractalList<Egg>>(builder).await?;
-
-        Ok(res.data.iter().map(|e| e.attributes.clone()).collect())
+        res
     }
 }
 
+impl_paginate!(GetEggs, Egg, "eggs");
+
 #[derive(Debug)]
 pub struct GetEgg<'a> {
     app: &'a Application,
@@ -104,6 +123,7 @@ pub struct GetEgg<'a> {
     with_nest: bool,
     with_script: bool,
     with_servers: bool,
+    with_variables: bool,
 }
 
 impl<'a> GetEgg<'a> {
@@ -117,6 +137,7 @@ impl<'a> GetEgg<'a> {
             with_nest: false,
             with_script: false,
             with_servers: false,
+            with_variables: false,
         }
     }
 
@@ -160,6 +181,16 @@ impl<'a> GetEgg<'a> {
         self
     }
 
+    /// Include the [`variables`] of the egg in the egg [`relationships`].
+    ///
+    /// [`variables`]: pteroxide_models::application::EggVariable
+    /// [`relationships`]: pteroxide_models::application::EggRelations
+    pub fn with_variables(mut self, value: bool) -> Self {
+        self.with_variables = value;
+
+        self
+    }
+
     /// Asynchronously executes the request and returns a [`Egg`] object.
     ///
     /// ## Errors
@@ -186,6 +217,9 @@ impl<'a> GetEgg<'a> {
         if self.with_servers {
             builder = builder.include("servers");
         }
+        if self.with_variables {
+            builder = builder.include("variables");
+        }
 
         let res = self.app.request::<FractalItem<Egg>>(builder).await?;
 
@@ -1,16 +1,22 @@
-use pteroxide_models::{
-    application::Location,
-    fractal::{FractalItem, FractalList},
-};
+use pteroxide_models::{application::Location, fractal::FractalItem};
 use serde::Serialize;
 
-use crate::{routing::Application as Route, Application, Builder, Error};
+use crate::{
+    query::{LocationFilter, LocationSort, SortOrder},
+    response::{impl_paginate, Response},
+    routing::Application as Route,
+    Application, Builder, Error,
+};
 
 #[derive(Debug)]
 pub struct GetLocations<'a> {
     app: &'a Application,
     with_nodes: bool,
     with_servers: bool,
+    filters: Vec<LocationFilter<'a>>,
+    sort: Option<(LocationSort, SortOrder)>,
+    page: u32,
+    per_page: u32,
 }
 
 impl<'a> GetLocations<'a> {
@@ -20,6 +26,10 @@ impl<'a> GetLocations<'a> {
             app,
             with_nodes: false,
             with_servers: false,
+            filters: Vec::new(),
+            sort: None,
+            page: 1,
+            per_page: 50,
         }
     }
 
@@ -44,27 +54,47 @@ impl<'a> GetLocations<'a> {
         self
     }
 
-    /// Asynchronously executes the request and returns a list of [`Location`] objects.
-    ///
-    /// ## Errors
-    ///
-    /// Returns an [`Error`] if the request fails.
-    pub async fn exec(&self) -> Result<Vec<Location>, Error> {
-        let mut builder = Builder::new(Route::GetLocations.into());
+    /// Adds a [`LocationFilter`] to filter the locations by.
+    pub fn filter(mut self, filter: LocationFilter<'a>) -> Self {
+        self.filters.push(filter);
+
+        self
+    }
+
+    /// Sorts the locations by the given [`LocationSort`] field in the given [`SortOrder`].
+    pub fn sort(mut self, field: LocationSort, order: SortOrder) -> Self {
+        self.sort = Some((field, order));
+
+        self
+    }
+
+    /// Returns a paginated [`Response`] for the request, which can fetch individual pages, collect
+    /// all pages or lazily walk through them as a stream.
+    pub fn paginate(&self) -> Response<'a, Location> {
+        let mut res = Response::new(&self.app.http, Route::GetLocations.into())
+            .page(self.page)
+            .per_page(self.per_page);
 
         if self.with_nodes {
-            builder = builder.include("nodes");
+            res = res.include("nodes");
         }
         if self.with_servers {
-            builder = builder.include("servers");
+            res = res.include("servers");
+        }
+        for filter in &self.filters {
+            let (key, value) = filter.param();
+            res = res.param(key, &value);
+        }
+        if let Some((field, order)) = self.sort {
+            res = res.param("sort", &order.with_field(field.as_str()));
         }
 
-        let res = self.app.request::<FractalList<Location>>(builder).await?;
-
-        Ok(res.data.iter().map(|l| l.attributes.clone()).collect())
+        res
     }
 }
 
+impl_paginate!(GetLocations, Location, "locations");
+
 #[derive(Debug)]
 pub struct GetLocation<'a> {
     app: &'a Application,
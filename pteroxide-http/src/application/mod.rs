@@ -1,29 +1,28 @@
-use hyper::{
-    body::{self, Buf},
-    client::HttpConnector,
-    header::{ACCEPT, AUTHORIZATION, CONTENT_TYPE, USER_AGENT},
-    Client as HClient, Request, StatusCode,
-};
+#[cfg(feature = "native-tls")]
+use hyper::Client as HClient;
+#[cfg(feature = "native-tls")]
 use hyper_tls::HttpsConnector;
-use pteroxide_models::fractal::FractalError;
 use serde::Deserialize;
-use serde_json::{self, Value};
 
 use self::{
     allocations::{CreateAllocations, DeleteAllocation, GetAllocations},
+    builder::ApplicationBuilder,
     eggs::{GetEgg, GetEggs},
     locations::{CreateLocation, DeleteLocation, GetLocation, GetLocations, UpdateLocation},
     nests::{GetNest, GetNests},
     nodes::{CreateNode, DeleteNode, GetNode, GetNodeConfiguration, GetNodes, UpdateNode},
     servers::{
-        CreateServer, DeleteServer, GetServer, GetServers, ReinstallServer, SuspendServer,
-        UnsuspendServer, UpdateServerBuild, UpdateServerDetails, UpdateServerStartup,
+        CreateServer, CreateServerDatabase, DeleteServer, DeleteServerDatabase, GetServer,
+        GetServerDatabase, GetServerDatabases, GetServers, ReinstallServer,
+        ResetServerDatabasePassword, SuspendServer, UnsuspendServer, UpdateServerBuild,
+        UpdateServerDetails, UpdateServerStartup,
     },
     users::{CreateUser, DeleteUser, GetUser, GetUsers, UpdateUser},
 };
-use super::{error::*, Builder};
+use super::{error::*, http::Http, ratelimit::RetryPolicy, transport::Transport, Builder};
 
 pub mod allocations;
+pub mod builder;
 pub mod eggs;
 pub mod locations;
 pub mod nests;
@@ -31,77 +30,76 @@ pub mod nodes;
 pub mod servers;
 pub mod users;
 
+pub(crate) const DEFAULT_USER_AGENT: &str = "Pteroxide HTTP Application";
+
 /// The main interface for interacting with the application API.
 #[derive(Debug)]
 pub struct Application {
-    http: HClient<HttpsConnector<HttpConnector>>,
-    url: String,
-    key: String,
+    pub(crate) http: Http,
 }
 
 impl Application {
-    /// Constructs a new [`Application`] with the given API credentials.
-    pub fn new(url: String, mut key: String) -> Self {
+    /// Constructs a new [`Application`] with the given API credentials, using a hyper client
+    /// with a [`native-tls`](hyper_tls) connector.
+    #[cfg(feature = "native-tls")]
+    pub fn new(url: String, key: String) -> Self {
         let conn = HttpsConnector::new();
-        if !key.starts_with("Bearer ") {
-            key.insert_str(0, "Bearer ");
-        }
 
+        Self::with_transport(url, key, HClient::builder().build(conn))
+    }
+
+    /// Constructs a new [`Application`] with the given API credentials, sending requests through
+    /// the given [`Transport`].
+    pub fn with_transport<T>(url: String, key: String, transport: T) -> Self
+    where
+        T: Transport + 'static,
+    {
         Self {
-            http: HClient::builder().build(conn),
-            url,
-            key,
+            http: Http::new(url, key, Box::new(transport), DEFAULT_USER_AGENT),
         }
     }
 
+    /// Returns an [`ApplicationBuilder`] for configuring the interface with the given API
+    /// credentials.
+    pub fn builder(url: String, key: String) -> ApplicationBuilder {
+        ApplicationBuilder::new(url, key)
+    }
+
+    /// Sets the [`RetryPolicy`] to use when a request is ratelimited and returns the interface.
+    /// By default, ratelimited requests are not retried and return a [`RatelimitError`].
+    ///
+    /// [`RatelimitError`]: ErrorKind::RatelimitError
+    pub fn retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.http.retry_policy = Some(policy);
+
+        self
+    }
+
     /// Performs an API request using the [`Builder`] with the set fields. Returns a result with
     /// the deserialized API response, if any.
     ///
     /// ## Example
     ///
     /// ```no_run
-    /// let builder = Builder::new(Route::GetUser { id: 2 });
+    /// # use pteroxide_http::{routing::Application as Route, Application, Builder, Error};
+    /// # use pteroxide_models::{application::User, fractal::FractalItem};
+    /// # async fn run(app: Application) -> Result<(), Error> {
+    /// let builder = Builder::new(Route::GetUser { id: 2 }.into());
     /// let data = app.request::<FractalItem<User>>(builder).await?;
     /// println!("{:#?}", data.attributes);
+    /// # Ok(())
+    /// # }
     /// ```
     ///
     /// ## Errors
     ///
-    /// Returns an [`Error`] if the request fails or if the response fails to be deserialized.
-    pub async fn request<T>(&self, mut builder: Builder) -> Result<T, Error>
+    /// Returns an [`Error`] if the request fails, if the request is ratelimited and cannot be
+    /// retried, or if the response fails to be deserialized.
+    pub async fn request<T>(&self, builder: Builder) -> Result<T, Error>
     where
         for<'de> T: Deserialize<'de>,
     {
-        let req = Request::builder()
-            .uri(builder.uri(self.url.clone())) // problematic
-            .method(builder.method)
-            .header(USER_AGENT, "Pteroxide HTTP Application")
-            .header(AUTHORIZATION, self.key.clone())
-            .header(CONTENT_TYPE, builder.content_type)
-            .header(ACCEPT, builder.accept_type)
-            .body(builder.body)?;
-
-        let res = self.http.request(req).await?;
-        match res.status() {
-            StatusCode::OK | StatusCode::CREATED | StatusCode::ACCEPTED => {
-                let buf = body::aggregate(res).await?;
-                let data = serde_json::from_reader(buf.reader())
-                    .expect("failed to deserialize into model");
-
-                Ok(data)
-            }
-            StatusCode::NO_CONTENT => serde_json::from_value(Value::Null).map_err(|_| Error {
-                kind: ErrorKind::DeserializeError,
-                source: None,
-            }),
-            _ => {
-                let buf = body::aggregate(res).await?;
-                let data = serde_json::from_reader::<_, FractalError>(buf.reader())
-                    .expect("failed to deserialize into model");
-
-                Err(Error::from(data))
-            }
-        }
+        self.http.request(builder).await
     }
 
     /// Returns a request builder for getting a list of [`User`]s.
@@ -118,6 +116,13 @@ impl Application {
         GetUser::new(self, id)
     }
 
+    /// Returns a request builder for getting a [`User`] by its external ID.
+    ///
+    /// [`User`]: pteroxide_models::application::User
+    pub fn get_user_by_external_id(&self, id: &str) -> GetUser<'_> {
+        GetUser::external(self, id)
+    }
+
     /// Returns a request builder for creating a [`User`].
     ///
     /// [`User`]: pteroxide_models::application::User
@@ -153,6 +158,13 @@ impl Application {
         GetServer::new(self, id)
     }
 
+    /// Returns a request builder for getting a [`Server`] by its external ID.
+    ///
+    /// [`Server`]: pteroxide_models::application::Server
+    pub fn get_server_by_external_id(&self, id: &str) -> GetServer<'_> {
+        GetServer::external(self, id)
+    }
+
     /// Returns a request builder for creating a [`Server`].
     ///
     /// [`Server`]: pteroxide_models::application::Server
@@ -209,6 +221,45 @@ impl Application {
         DeleteServer::new(self, id)
     }
 
+    /// Returns a request builder for getting a list of a server's [`Database`]s.
+    ///
+    /// [`Database`]: pteroxide_models::application::Database
+    pub const fn get_server_databases(&self, id: i32) -> GetServerDatabases<'_> {
+        GetServerDatabases::new(self, id)
+    }
+
+    /// Returns a request builder for getting a specified [`Database`] of a server.
+    ///
+    /// [`Database`]: pteroxide_models::application::Database
+    pub const fn get_server_database(&self, id: i32, database: i32) -> GetServerDatabase<'_> {
+        GetServerDatabase::new(self, id, database)
+    }
+
+    /// Returns a request builder for creating a [`Database`] for a server.
+    ///
+    /// [`Database`]: pteroxide_models::application::Database
+    pub fn create_server_database(&self, id: i32) -> CreateServerDatabase<'_> {
+        CreateServerDatabase::new(self, id)
+    }
+
+    /// Returns a request builder for resetting the password of a server's [`Database`].
+    ///
+    /// [`Database`]: pteroxide_models::application::Database
+    pub const fn reset_server_database_password(
+        &self,
+        id: i32,
+        database: i32,
+    ) -> ResetServerDatabasePassword<'_> {
+        ResetServerDatabasePassword::new(self, id, database)
+    }
+
+    /// Returns a request builder for deleting a server's [`Database`].
+    ///
+    /// [`Database`]: pteroxide_models::application::Database
+    pub const fn delete_server_database(&self, id: i32, database: i32) -> DeleteServerDatabase<'_> {
+        DeleteServerDatabase::new(self, id, database)
+    }
+
     /// Returns a request builder for getting a list of [`Node`]s.
     ///
     /// [`Node`]: pteroxide_models::application::Node
@@ -1,15 +1,18 @@
-use pteroxide_models::{
-    application::Nest,
-    fractal::{FractalItem, FractalList},
-};
+use pteroxide_models::{application::Nest, fractal::FractalItem};
 
-use crate::{routing::Application as Route, Application, Builder, Error};
+use crate::{
+    response::{impl_paginate, Response},
+    routing::Application as Route,
+    Application, Builder, Error,
+};
 
 #[derive(Debug)]
 pub struct GetNests<'a> {
     app: &'a Application,
     with_eggs: bool,
     with_servers: bool,
+    page: u32,
+    per_page: u32,
 }
 
 impl<'a> GetNests<'a> {
@@ -19,6 +22,8 @@ impl<'a> GetNests<'a> {
             app,
             with_eggs: false,
             with_servers: false,
+            page: 1,
+            per_page: 50,
         }
     }
 
@@ -42,27 +47,26 @@ impl<'a> GetNests<'a> {
         self
     }
 
-    /// Asynchronously executes the request and returns a list of [`Nest`] objects.
-    ///
-    /// ## Errors
-    ///
-    /// Returns an [`Error`] if the request fails.
-    pub async fn exec(&self) -> Result<Vec<Nest>, Error> {
-        let mut builder = Builder::new(Route::GetNests.into());
+    /// Returns a paginated [`Response`] for the request, which can fetch individual pages, collect
+    /// all pages or lazily walk through them as a stream.
+    pub fn paginate(&self) -> Response<'a, Nest> {
+        let mut res = Response::new(&self.app.http, Route::GetNests.into())
+            .page(self.page)
+            .per_page(self.per_page);
 
         if self.with_eggs {
-            builder = builder.include("eggs");
+            res = res.include("eggs");
         }
         if self.with_servers {
-            builder = builder.include("servers");
+            res = res.include("servers");
         }
 
-        let res = self.app.request::<FractalList<Nest>>(builder).await?;
-
-        Ok(res.data.iter().map(|n| n.attributes.clone()).collect())
+        res
     }
 }
 
+impl_paginate!(GetNests, Nest, "nests");
+
 #[derive(Debug)]
 pub struct GetNest<'a> {
     app: &'a Application,
@@ -1,10 +1,15 @@
 use pteroxide_models::{
     application::{Node, NodeConfiguration},
-    fractal::{FractalItem, FractalList},
+    fractal::FractalItem,
 };
 use serde::Serialize;
 
-use crate::{routing::Application as Route, Application, Builder, Error};
+use crate::{
+    query::{NodeFilter, NodeSort, SortOrder},
+    response::{impl_paginate, Response},
+    routing::Application as Route,
+    Application, Builder, Error,
+};
 
 #[derive(Debug)]
 pub struct GetNodes<'a> {
@@ -12,6 +17,10 @@ pub struct GetNodes<'a> {
     with_allocations: bool,
     with_location: bool,
     with_servers: bool,
+    filters: Vec<NodeFilter<'a>>,
+    sort: Option<(NodeSort, SortOrder)>,
+    page: u32,
+    per_page: u32,
 }
 
 impl<'a> GetNodes<'a> {
@@ -22,6 +31,10 @@ impl<'a> GetNodes<'a> {
             with_allocations: false,
             with_location: false,
             with_servers: false,
+            filters: Vec::new(),
+            sort: None,
+            page: 1,
+            per_page: 50,
         }
     }
 
@@ -55,30 +68,50 @@ impl<'a> GetNodes<'a> {
         self
     }
 
-    /// Asynchronously executes the request and returns a list of [`Node`] objects.
-    ///
-    /// ## Errors
-    ///
-    /// Returns an [`Error`] if the request fails.
-    pub async fn exec(&self) -> Result<Vec<Node>, Error> {
-        let mut builder = Builder::new(Route::GetNodes.into());
+    /// Adds a [`NodeFilter`] to filter the nodes by.
+    pub fn filter(mut self, filter: NodeFilter<'a>) -> Self {
+        self.filters.push(filter);
+
+        self
+    }
+
+    /// Sorts the nodes by the given [`NodeSort`] field in the given [`SortOrder`].
+    pub fn sort(mut self, field: NodeSort, order: SortOrder) -> Self {
+        self.sort = Some((field, order));
+
+        self
+    }
+
+    /// Returns a paginated [`Response`] for the request, which can fetch individual pages, collect
+    /// all pages or lazily walk through them as a stream.
+    pub fn paginate(&self) -> Response<'a, Node> {
+        let mut res = Response::new(&self.app.http, Route::GetNodes.into())
+            .page(self.page)
+            .per_page(self.per_page);
 
         if self.with_allocations {
-            builder = builder.include("allocations");
+            res = res.include("allocations");
         }
         if self.with_location {
-            builder = builder.include("location");
+            res = res.include("location");
         }
         if self.with_servers {
-            builder = builder.include("servers");
+            res = res.include("servers");
+        }
+        for filter in &self.filters {
+            let (key, value) = filter.param();
+            res = res.param(key, &value);
+        }
+        if let Some((field, order)) = self.sort {
+            res = res.param("sort", &order.with_field(field.as_str()));
         }
 
-        let res = self.app.request::<FractalList<Node>>(builder).await?;
-
-        Ok(res.data.iter().map(|n| n.attributes.clone()).collect())
+        res
     }
 }
 
+impl_paginate!(GetNodes, Node, "nodes");
+
 #[derive(Debug)]
 pub struct GetNode<'a> {
     app: &'a Application,
@@ -111,8 +111,8 @@ impl<'a> CreateServer<'a> {
     }
 
     /// Sets an environment variable for the server. This is required with certain eggs.
-    pub fn env_variable(mut self, name: &'a str, value: Value) -> Self {
-        self.fields.environment.insert(name, value);
+    pub fn env_variable(mut self, name: &'a str, value: impl Into<Value>) -> Self {
+        self.fields.environment.insert(name, value.into());
 
         self
     }
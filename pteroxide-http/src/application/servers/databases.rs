@@ -0,0 +1,264 @@
+use pteroxide_models::{
+    application::Database,
+    fractal::{FractalItem, FractalList},
+};
+use serde::Serialize;
+
+use crate::{routing::Application as Route, Application, Builder, Error};
+
+#[derive(Debug)]
+pub struct GetServerDatabases<'a> {
+    app: &'a Application,
+    id: i32,
+    with_password: bool,
+    with_host: bool,
+}
+
+impl<'a> GetServerDatabases<'a> {
+    #[doc(hidden)]
+    pub const fn new(app: &'a Application, id: i32) -> Self {
+        Self {
+            app,
+            id,
+            with_password: false,
+            with_host: false,
+        }
+    }
+
+    /// Include the password of each database in the database [`relationships`].
+    ///
+    /// [`relationships`]: pteroxide_models::application::DatabaseRelations
+    pub fn with_password(mut self, value: bool) -> Self {
+        self.with_password = value;
+
+        self
+    }
+
+    /// Include the [`host`] each database is on in the database [`relationships`].
+    ///
+    /// [`host`]: pteroxide_models::application::DatabaseHost
+    /// [`relationships`]: pteroxide_models::application::DatabaseRelations
+    pub fn with_host(mut self, value: bool) -> Self {
+        self.with_host = value;
+
+        self
+    }
+
+    /// Asynchronously executes the request and returns a list of [`Database`] objects.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an [`Error`] if the request fails or the server is not found.
+    pub async fn exec(&self) -> Result<Vec<Database>, Error> {
+        let mut builder = Builder::new(Route::GetServerDatabases { id: self.id }.into());
+
+        if self.with_password {
+            builder = builder.include("password");
+        }
+        if self.with_host {
+            builder = builder.include("host");
+        }
+
+        let res = self.app.request::<FractalList<Database>>(builder).await?;
+
+        Ok(res.data.into_iter().map(|d| d.attributes).collect())
+    }
+}
+
+#[derive(Debug)]
+pub struct GetServerDatabase<'a> {
+    app: &'a Application,
+    id: i32,
+    database: i32,
+    with_password: bool,
+    with_host: bool,
+}
+
+impl<'a> GetServerDatabase<'a> {
+    #[doc(hidden)]
+    pub const fn new(app: &'a Application, id: i32, database: i32) -> Self {
+        Self {
+            app,
+            id,
+            database,
+            with_password: false,
+            with_host: false,
+        }
+    }
+
+    /// Include the password of the database in the database [`relationships`].
+    ///
+    /// [`relationships`]: pteroxide_models::application::DatabaseRelations
+    pub fn with_password(mut self, value: bool) -> Self {
+        self.with_password = value;
+
+        self
+    }
+
+    /// Include the [`host`] the database is on in the database [`relationships`].
+    ///
+    /// [`host`]: pteroxide_models::application::DatabaseHost
+    /// [`relationships`]: pteroxide_models::application::DatabaseRelations
+    pub fn with_host(mut self, value: bool) -> Self {
+        self.with_host = value;
+
+        self
+    }
+
+    /// Asynchronously executes the request and returns a [`Database`] object.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an [`Error`] if the request fails or the database is not found.
+    pub async fn exec(&self) -> Result<Database, Error> {
+        let mut builder = Builder::new(
+            Route::GetServerDatabase {
+                id: self.id,
+                database: self.database,
+            }
+            .into(),
+        );
+
+        if self.with_password {
+            builder = builder.include("password");
+        }
+        if self.with_host {
+            builder = builder.include("host");
+        }
+
+        let res = self.app.request::<FractalItem<Database>>(builder).await?;
+
+        Ok(res.attributes)
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+struct CreateServerDatabaseFields<'a> {
+    pub database: &'a str,
+    pub remote: &'a str,
+    pub host: i32,
+}
+
+#[derive(Debug)]
+pub struct CreateServerDatabase<'a> {
+    app: &'a Application,
+    id: i32,
+    fields: CreateServerDatabaseFields<'a>,
+}
+
+impl<'a> CreateServerDatabase<'a> {
+    #[doc(hidden)]
+    pub fn new(app: &'a Application, id: i32) -> Self {
+        Self {
+            app,
+            id,
+            fields: CreateServerDatabaseFields {
+                remote: "%",
+                ..Default::default()
+            },
+        }
+    }
+
+    /// Sets the name of the database. Note that the panel prefixes the name with the server ID.
+    #[must_use = "a database requires a name"]
+    pub fn database(mut self, database: &'a str) -> Self {
+        self.fields.database = database;
+
+        self
+    }
+
+    /// Sets the IP addresses allowed to connect to the database, where `%` is a wildcard.
+    /// Defaults to `%` (any address).
+    pub fn remote(mut self, remote: &'a str) -> Self {
+        self.fields.remote = remote;
+
+        self
+    }
+
+    /// Sets the ID of the [`host`] to create the database on.
+    ///
+    /// [`host`]: pteroxide_models::application::DatabaseHost
+    #[must_use = "a database must be created on a host"]
+    pub fn host(mut self, host: i32) -> Self {
+        self.fields.host = host;
+
+        self
+    }
+
+    /// Asynchronously executes the request and returns the new [`Database`] object.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an [`Error`] if the request fails, if the server has reached its database limit or
+    /// a field does not satisfy a validation rule.
+    pub async fn exec(self) -> Result<Database, Error> {
+        let builder =
+            Builder::new(Route::CreateServerDatabase { id: self.id }.into()).json(self.fields);
+        let res = self.app.request::<FractalItem<Database>>(builder).await?;
+
+        Ok(res.attributes)
+    }
+}
+
+#[derive(Debug)]
+pub struct ResetServerDatabasePassword<'a> {
+    app: &'a Application,
+    id: i32,
+    database: i32,
+}
+
+impl<'a> ResetServerDatabasePassword<'a> {
+    #[doc(hidden)]
+    pub const fn new(app: &'a Application, id: i32, database: i32) -> Self {
+        Self { app, id, database }
+    }
+
+    /// Asynchronously executes the request and returns nothing. The new password can be fetched
+    /// with [`GetServerDatabase::with_password`].
+    ///
+    /// ## Errors
+    ///
+    /// Returns an [`Error`] if the request fails or the database is not found.
+    pub async fn exec(&self) -> Result<(), Error> {
+        self.app
+            .request::<()>(Builder::new(
+                Route::ResetServerDatabasePassword {
+                    id: self.id,
+                    database: self.database,
+                }
+                .into(),
+            ))
+            .await
+    }
+}
+
+#[derive(Debug)]
+pub struct DeleteServerDatabase<'a> {
+    app: &'a Application,
+    id: i32,
+    database: i32,
+}
+
+impl<'a> DeleteServerDatabase<'a> {
+    #[doc(hidden)]
+    pub const fn new(app: &'a Application, id: i32, database: i32) -> Self {
+        Self { app, id, database }
+    }
+
+    /// Asynchronously executes the request and returns nothing.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an [`Error`] if the request fails or the database is not found.
+    pub async fn exec(&self) -> Result<(), Error> {
+        self.app
+            .request::<()>(Builder::new(
+                Route::DeleteServerDatabase {
+                    id: self.id,
+                    database: self.database,
+                }
+                .into(),
+            ))
+            .await
+    }
+}
@@ -1,9 +1,11 @@
-use pteroxide_models::{
-    application::Server,
-    fractal::{FractalItem, FractalList},
-};
+use pteroxide_models::{application::Server, fractal::FractalItem};
 
-use crate::{routing::Application as Route, Application, Builder, Error};
+use crate::{
+    query::{ServerFilter, ServerSort, SortOrder},
+    response::{impl_paginate, Response},
+    routing::Application as Route,
+    Application, Builder, Error,
+};
 
 #[derive(Debug)]
 pub struct GetServers<'a> {
@@ -13,11 +15,15 @@ pub struct GetServers<'a> {
     with_subusers: bool,
     with_nest: bool,
     with_egg: bool,
-    // with_variables: bool,
+    with_variables: bool,
     with_location: bool,
     with_node: bool,
-    // with_databases: bool,
+    with_databases: bool,
     // not doing that transfer bs
+    filters: Vec<ServerFilter<'a>>,
+    sort: Option<(ServerSort, SortOrder)>,
+    page: u32,
+    per_page: u32,
 }
 
 impl<'a> GetServers<'a> {
@@ -30,8 +36,14 @@ impl<'a> GetServers<'a> {
             with_subusers: false,
             with_nest: false,
             with_egg: false,
+            with_variables: false,
             with_location: false,
             with_node: false,
+            with_databases: false,
+            filters: Vec::new(),
+            sort: None,
+            page: 1,
+            per_page: 50,
         }
     }
 
@@ -85,6 +97,17 @@ impl<'a> GetServers<'a> {
         self
     }
 
+    /// Include the [`variables`] of the server's egg with the values set for the server in the
+    /// server [`relationships`].
+    ///
+    /// [`variables`]: pteroxide_models::application::EggVariable
+    /// [`relationships`]: pteroxide_models::application::ServerRelations
+    pub fn with_variables(mut self, value: bool) -> Self {
+        self.with_variables = value;
+
+        self
+    }
+
     /// Include the [`location`] the server's node is part of in the server [`relationships`].
     ///
     /// [`location`]: pteroxide_models::application::Location
@@ -105,70 +128,117 @@ impl<'a> GetServers<'a> {
         self
     }
 
-    /// Asynchronously executes the request and returns a list of [`Server`] objects.
-    ///
-    /// ## Errors
+    /// Include the server [`Database`]s in the server [`relationships`].
     ///
-    /// Returns an [`Error`] if the request fails.
-    pub async fn exec(&self) -> Result<Vec<Server>, Error> {
-        let mut builder = Builder::new(Route::GetServers.into());
+    /// [`Database`]: pteroxide_models::application::Database
+    /// [`relationships`]: pteroxide_models::application::ServerRelations
+    pub fn with_databases(mut self, value: bool) -> Self {
+        self.with_databases = value;
+
+        self
+    }
+
+    /// Adds a [`ServerFilter`] to filter the servers by.
+    pub fn filter(mut self, filter: ServerFilter<'a>) -> Self {
+        self.filters.push(filter);
+
+        self
+    }
+
+    /// Sorts the servers by the given [`ServerSort`] field in the given [`SortOrder`].
+    pub fn sort(mut self, field: ServerSort, order: SortOrder) -> Self {
+        self.sort = Some((field, order));
+
+        self
+    }
+
+    /// Returns a paginated [`Response`] for the request, which can fetch individual pages, collect
+    /// all pages or lazily walk through them as a stream.
+    pub fn paginate(&self) -> Response<'a, Server> {
+        let mut res = Response::new(&self.app.http, Route::GetServers.into())
+            .page(self.page)
+            .per_page(self.per_page);
 
         if self.with_allocations {
-            builder = builder.include("allocations");
+            res = res.include("allocations");
         }
         if self.with_owner {
-            builder = builder.include("user");
+            res = res.include("user");
         }
         if self.with_subusers {
-            builder = builder.include("subusers");
+            res = res.include("subusers");
         }
         if self.with_nest {
-            builder = builder.include("nest");
+            res = res.include("nest");
         }
         if self.with_egg {
-            builder = builder.include("egg");
+            res = res.include("egg");
+        }
+        if self.with_variables {
+            res = res.include("variables");
         }
         if self.with_location {
-            builder = builder.include("location");
+            res = res.include("location");
         }
         if self.with_node {
-            builder = builder.include("node");
+            res = res.include("node");
+        }
+        if self.with_databases {
+            res = res.include("databases");
+        }
+        for filter in &self.filters {
+            let (key, value) = filter.param();
+            res = res.param(key, &value);
+        }
+        if let Some((field, order)) = self.sort {
+            res = res.param("sort", &order.with_field(field.as_str()));
         }
 
-        let res = self.app.request::<FractalList<Server>>(builder).await?;
-
-        Ok(res.data.iter().map(|s| s.attributes.clone()).collect())
+        res
     }
 }
 
+impl_paginate!(GetServers, Server, "servers");
+
 #[derive(Debug)]
 pub struct GetServer<'a> {
     app: &'a Application,
-    id: i32,
+    route: Route,
     with_allocations: bool,
     with_owner: bool,
     with_subusers: bool,
     with_nest: bool,
     with_egg: bool,
-    // with_variables: bool,
+    with_variables: bool,
     with_location: bool,
     with_node: bool,
-    // with_databases: bool,
+    with_databases: bool,
 }
 
 impl<'a> GetServer<'a> {
     #[doc(hidden)]
     pub const fn new(app: &'a Application, id: i32) -> Self {
+        Self::with_route(app, Route::GetServer { id })
+    }
+
+    #[doc(hidden)]
+    pub fn external(app: &'a Application, id: &str) -> Self {
+        Self::with_route(app, Route::GetServerByExternalId { id: id.to_string() })
+    }
+
+    const fn with_route(app: &'a Application, route: Route) -> Self {
         Self {
             app,
-            id,
+            route,
             with_allocations: false,
             with_owner: false,
             with_subusers: false,
             with_nest: false,
             with_egg: false,
+            with_variables: false,
             with_location: false,
             with_node: false,
+            with_databases: false,
         }
     }
 
@@ -222,6 +292,17 @@ impl<'a> GetServer<'a> {
         self
     }
 
+    /// Include the [`variables`] of the server's egg with the values set for the server in the
+    /// server [`relationships`].
+    ///
+    /// [`variables`]: pteroxide_models::application::EggVariable
+    /// [`relationships`]: pteroxide_models::application::ServerRelations
+    pub fn with_variables(mut self, value: bool) -> Self {
+        self.with_variables = value;
+
+        self
+    }
+
     /// Include the [`location`] the server's node is part of in the server [`relationships`].
     ///
     /// [`location`]: pteroxide_models::application::Location
@@ -242,13 +323,23 @@ impl<'a> GetServer<'a> {
         self
     }
 
+    /// Include the server [`Database`]s in the server [`relationships`].
+    ///
+    /// [`Database`]: pteroxide_models::application::Database
+    /// [`relationships`]: pteroxide_models::application::ServerRelations
+    pub fn with_databases(mut self, value: bool) -> Self {
+        self.with_databases = value;
+
+        self
+    }
+
     /// Asynchronously executes the request and returns a [`Server`] object.
     ///
     /// ## Errors
     ///
     /// Returns an [`Error`] if the request fails or the server is not found.
     pub async fn exec(&self) -> Result<Server, Error> {
-        let mut builder = Builder::new(Route::GetServer { id: self.id }.into());
+        let mut builder = Builder::new(self.route.clone().into());
 
         if self.with_allocations {
             builder = builder.include("allocations");
@@ -265,12 +356,18 @@ impl<'a> GetServer<'a> {
         if self.with_egg {
             builder = builder.include("egg");
         }
+        if self.with_variables {
+            builder = builder.include("variables");
+        }
         if self.with_location {
             builder = builder.include("location");
         }
         if self.with_node {
             builder = builder.include("node");
         }
+        if self.with_databases {
+            builder = builder.include("databases");
+        }
 
         let res = self.app.request::<FractalItem<Server>>(builder).await?;
 
@@ -1,6 +1,7 @@
 pub mod control;
 pub mod create;
+pub mod databases;
 pub mod get;
 pub mod update;
 
-pub use self::{control::*, create::*, get::*, update::*};
+pub use self::{control::*, create::*, databases::*, get::*, update::*};
@@ -193,7 +193,7 @@ impl<'a> UpdateServerDetails<'a> {
 #[derive(Debug, Default, Serialize)]
 struct UpdateServerStartupFields<'a> {
     pub startup: &'a str,
-    pub environment: HashMap<&'a str, Value>,
+    pub environment: HashMap<String, Value>,
     pub egg: i32,
     pub image: &'a str,
 }
@@ -222,15 +222,24 @@ impl<'a> UpdateServerStartup<'a> {
         self
     }
 
-    /// Sets an environment variable for the server.
+    /// Sets an environment variable for the server. Variables that are not set default to the
+    /// current values in the server [`environment`].
     ///
-    /// ### Warning
+    /// [`environment`]: pteroxide_models::application::Container::environment
+    pub fn env_variable(mut self, key: &str, value: impl Into<Value>) -> Self {
+        self.fields
+            .environment
+            .insert(key.to_string(), value.into());
+
+        self
+    }
+
+    /// Sets the environment variables for the server from a map, such as a modified copy of the
+    /// server [`environment`]. This overwrites any variables set with the same keys.
     ///
-    /// Due to the complexity of this field, the current server environment variables **will not**
-    /// be filled in by default like other update methods. You need to set every variable required
-    /// by the egg/server in this request.
-    pub fn env_variable(mut self, key: &'a str, value: Value) -> Self {
-        self.fields.environment.insert(key, value);
+    /// [`environment`]: pteroxide_models::application::Container::environment
+    pub fn environment(mut self, environment: HashMap<String, Value>) -> Self {
+        self.fields.environment.extend(environment);
 
         self
     }
@@ -266,6 +275,9 @@ impl<'a> UpdateServerStartup<'a> {
         if self.fields.image.is_empty() {
             self.fields.image = server.container.image.as_str();
         }
+        for (key, value) in server.container.environment {
+            self.fields.environment.entry(key).or_insert(value);
+        }
 
         let builder =
             Builder::new(Route::UpdateServerStartup { id: self.id }.into()).json(self.fields);
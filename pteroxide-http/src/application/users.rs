@@ -1,15 +1,21 @@
-use pteroxide_models::{
-    application::User,
-    fractal::{FractalItem, FractalList},
-};
+use pteroxide_models::{application::User, fractal::FractalItem};
 use serde::Serialize;
 
-use crate::{routing::Application as Route, Application, Builder, Error};
+use crate::{
+    query::{SortOrder, UserFilter, UserSort},
+    response::{impl_paginate, Response},
+    routing::Application as Route,
+    Application, Builder, Error,
+};
 
 #[derive(Debug)]
 pub struct GetUsers<'a> {
     app: &'a Application,
     with_servers: bool,
+    filters: Vec<UserFilter<'a>>,
+    sort: Option<(UserSort, SortOrder)>,
+    page: u32,
+    per_page: u32,
 }
 
 impl<'a> GetUsers<'a> {
@@ -18,6 +24,10 @@ impl<'a> GetUsers<'a> {
         Self {
             app,
             with_servers: false,
+            filters: Vec::new(),
+            sort: None,
+            page: 1,
+            per_page: 50,
         }
     }
 
@@ -31,36 +41,66 @@ impl<'a> GetUsers<'a> {
         self
     }
 
-    /// Asynchronously executes the request and returns a list of [`User`] objects.
-    ///
-    /// ## Errors
-    ///
-    /// Returns an [`Error`] if the request fails.
-    pub async fn exec(&self) -> Result<Vec<User>, Error> {
-        let mut builder = Builder::new(Route::GetUsers.into());
+    /// Adds a [`UserFilter`] to filter the users by.
+    pub fn filter(mut self, filter: UserFilter<'a>) -> Self {
+        self.filters.push(filter);
+
+        self
+    }
+
+    /// Sorts the users by the given [`UserSort`] field in the given [`SortOrder`].
+    pub fn sort(mut self, field: UserSort, order: SortOrder) -> Self {
+        self.sort = Some((field, order));
+
+        self
+    }
+
+    /// Returns a paginated [`Response`] for the request, which can fetch individual pages, collect
+    /// all pages or lazily walk through them as a stream.
+    pub fn paginate(&self) -> Response<'a, User> {
+        let mut res = Response::new(&self.app.http, Route::GetUsers.into())
+            .page(self.page)
+            .per_page(self.per_page);
+
         if self.with_servers {
-            builder = builder.param("include", "servers");
+            res = res.include("servers");
+        }
+        for filter in &self.filters {
+            let (key, value) = filter.param();
+            res = res.param(key, &value);
+        }
+        if let Some((field, order)) = self.sort {
+            res = res.param("sort", &order.with_field(field.as_str()));
         }
 
-        let res = self.app.request::<FractalList<User>>(builder).await?;
-
-        Ok(res.data.iter().map(|u| u.attributes.clone()).collect())
+        res
     }
 }
 
+impl_paginate!(GetUsers, User, "users");
+
 #[derive(Debug)]
 pub struct GetUser<'a> {
     app: &'a Application,
-    id: i32,
+    route: Route,
     with_servers: bool,
 }
 
 impl<'a> GetUser<'a> {
     #[doc(hidden)]
     pub const fn new(app: &'a Application, id: i32) -> Self {
+        Self::with_route(app, Route::GetUser { id })
+    }
+
+    #[doc(hidden)]
+    pub fn external(app: &'a Application, id: &str) -> Self {
+        Self::with_route(app, Route::GetUserByExternalId { id: id.to_string() })
+    }
+
+    const fn with_route(app: &'a Application, route: Route) -> Self {
         Self {
             app,
-            id,
+            route,
             with_servers: false,
         }
     }
@@ -81,7 +121,7 @@ impl<'a> GetUser<'a> {
     ///
     /// Returns an [`Error`] if the request fails or if the user is not found.
     pub async fn exec(&self) -> Result<User, Error> {
-        let mut builder = Builder::new(Route::GetUser { id: self.id }.into());
+        let mut builder = Builder::new(self.route.clone().into());
         if self.with_servers {
             builder = builder.param("include", "servers");
         }
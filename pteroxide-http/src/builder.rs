@@ -1,4 +1,4 @@
-use hyper::{header::HeaderValue, Body, Method};
+use hyper::{body::Bytes, header::HeaderValue, Method};
 use serde::Serialize;
 use serde_json::json;
 use urlencoding::encode;
@@ -11,7 +11,7 @@ pub struct Builder {
     pub(crate) route: String,
     pub(crate) params: Vec<(String, String)>,
     pub(crate) include: Vec<String>,
-    pub(crate) body: Body,
+    pub(crate) body: Bytes,
     pub(crate) content_type: HeaderValue,
     pub(crate) accept_type: HeaderValue,
 }
@@ -44,20 +44,13 @@ impl Builder {
             return url;
         }
 
-        let mut query = format!("?{}={}", self.params[0].0, self.params[0].1);
+        let query: Vec<String> = self
+            .params
+            .iter()
+            .map(|(k, v)| format!("{}={}", k, encode(v)))
+            .collect();
 
-        if self.params.len() > 1 {
-            let parts: Vec<String> = self
-                .params
-                .iter()
-                .skip(1)
-                .map(|(k, v)| format!("&{}={}", k, encode(v)))
-                .collect();
-
-            query.extend(parts);
-        }
-
-        format!("{}/{}", url, query)
+        format!("{}?{}", url, query.join("&"))
     }
 
     /// Sets the HTTP [`Route`] for the request and returns the builder. This also sets the default
@@ -76,11 +69,13 @@ impl Builder {
     /// ## Example
     ///
     /// ```no_run
-    /// let builder = Builder::default()
-    ///     .route(Route::GetUsers)
+    /// # use pteroxide_http::{routing::Application as Route, Builder};
+    /// let mut builder = Builder::default()
+    ///     .route(Route::GetUsers.into())
     ///     .param("include", "servers");
     ///
-    /// println!("{}", builder.uri()); // "/api/application/users?include=servers"
+    /// // "https://panel.example.com/api/application/users?include=servers"
+    /// println!("{}", builder.uri("https://panel.example.com".to_string()));
     /// ```
     pub fn param(mut self, key: &str, value: &str) -> Self {
         self.params.push((key.to_string(), value.to_string()));
@@ -94,11 +89,14 @@ impl Builder {
         self
     }
 
-    /// Sets the request [`Body`] to the given value and returns the builder. Defaults to empty.
+    /// Sets the request body to the given value and returns the builder. Defaults to empty. The
+    /// body is kept in memory so that the request can be retried if it is ratelimited.
     ///
     /// ## Example
     ///
     /// ```no_run
+    /// # use pteroxide_http::{routing::Application as Route, Builder};
+    /// # use serde_json::json;
     /// let value = json!({
     ///     "username": "test",
     ///     "email": "test@example.com",
@@ -107,39 +105,48 @@ impl Builder {
     /// });
     ///
     /// let builder = Builder::default()
-    ///     .route(Route::CreateUser)
+    ///     .route(Route::CreateUser.into())
     ///     .body(value.to_string());
     /// ```
     pub fn body<T>(mut self, body: T) -> Self
     where
-        Body: From<T>,
+        Bytes: From<T>,
     {
-        self.body = Body::from(body);
+        self.body = Bytes::from(body);
 
         self
     }
 
-    /// Sets the request [`Body`] to the JSON representation of the value and returns the builder.
+    /// Sets the request body to the JSON representation of the value and returns the builder.
     ///
     /// ## Example
     ///
     /// ```no_run
+    /// # use pteroxide_http::{routing::Application as Route, Builder};
+    /// # use serde::Serialize;
+    /// # #[derive(Serialize)]
+    /// # struct CreateUserFields<'a> {
+    /// #     username: &'a str,
+    /// #     email: &'a str,
+    /// #     first_name: &'a str,
+    /// #     last_name: &'a str,
+    /// # }
     /// let fields = CreateUserFields {
     ///     username: "test",
     ///     email: "test@example.com",
     ///     first_name: "test",
     ///     last_name: "example",
-    /// }
+    /// };
     ///
     /// let builder = Builder::default()
-    ///     .route(Route::CreateUser)
+    ///     .route(Route::CreateUser.into())
     ///     .json(fields);
     /// ```
     pub fn json<T>(mut self, body: T) -> Self
     where
         T: Serialize,
     {
-        self.body = Body::from(json!(body).to_string());
+        self.body = Bytes::from(json!(body).to_string());
 
         self
     }
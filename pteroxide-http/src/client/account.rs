@@ -0,0 +1,386 @@
+use pteroxide_models::{
+    client::{Account, ApiKey, TwoFactorData},
+    fractal::{FractalItem, FractalList},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{routing::Client as Route, Builder, Client, Error};
+
+#[derive(Debug)]
+pub struct GetAccount<'a> {
+    client: &'a Client,
+}
+
+impl<'a> GetAccount<'a> {
+    #[doc(hidden)]
+    pub const fn new(client: &'a Client) -> Self {
+        Self { client }
+    }
+
+    /// Asynchronously executes the request and returns the [`Account`] object.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an [`Error`] if the request fails.
+    pub async fn exec(&self) -> Result<Account, Error> {
+        let builder = Builder::new(Route::GetAccount.into());
+        let res = self.client.request::<FractalItem<Account>>(builder).await?;
+
+        Ok(res.attributes)
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+struct UpdateEmailFields<'a> {
+    pub email: &'a str,
+    pub password: &'a str,
+}
+
+#[derive(Debug)]
+pub struct UpdateEmail<'a> {
+    client: &'a Client,
+    fields: UpdateEmailFields<'a>,
+}
+
+impl<'a> UpdateEmail<'a> {
+    #[doc(hidden)]
+    pub fn new(client: &'a Client) -> Self {
+        Self {
+            client,
+            fields: Default::default(),
+        }
+    }
+
+    /// Sets the new email for the account. Note that this must be unique to the account;
+    /// duplicate emails are not allowed by the panel.
+    #[must_use = "an email must be set to update it"]
+    pub fn email(mut self, email: &'a str) -> Self {
+        self.fields.email = email;
+
+        self
+    }
+
+    /// Sets the current password of the account, required to confirm the change.
+    #[must_use = "the current password is required to update the email"]
+    pub fn password(mut self, password: &'a str) -> Self {
+        self.fields.password = password;
+
+        self
+    }
+
+    /// Asynchronously executes the request.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an [`Error`] if the request fails, the password is incorrect or the email does not
+    /// satisfy a validation rule.
+    pub async fn exec(self) -> Result<(), Error> {
+        let builder = Builder::new(Route::UpdateEmail.into()).json(self.fields);
+
+        self.client.request::<()>(builder).await
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+struct UpdatePasswordFields<'a> {
+    pub current_password: &'a str,
+    pub password: &'a str,
+    pub password_confirmation: &'a str,
+}
+
+#[derive(Debug)]
+pub struct UpdatePassword<'a> {
+    client: &'a Client,
+    fields: UpdatePasswordFields<'a>,
+}
+
+impl<'a> UpdatePassword<'a> {
+    #[doc(hidden)]
+    pub fn new(client: &'a Client) -> Self {
+        Self {
+            client,
+            fields: Default::default(),
+        }
+    }
+
+    /// Sets the current password of the account, required to confirm the change.
+    #[must_use = "the current password is required to update the password"]
+    pub fn current_password(mut self, password: &'a str) -> Self {
+        self.fields.current_password = password;
+
+        self
+    }
+
+    /// Sets the new password for the account. This also sets the password confirmation field, so
+    /// it does not need to be set separately.
+    #[must_use = "a new password must be set to update it"]
+    pub fn password(mut self, password: &'a str) -> Self {
+        self.fields.password = password;
+        self.fields.password_confirmation = password;
+
+        self
+    }
+
+    /// Asynchronously executes the request. Note that this invalidates the existing sessions of
+    /// the account on the panel, but not its API keys.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an [`Error`] if the request fails, the current password is incorrect or the new
+    /// password does not satisfy a validation rule.
+    pub async fn exec(self) -> Result<(), Error> {
+        let builder = Builder::new(Route::UpdatePassword.into()).json(self.fields);
+
+        self.client.request::<()>(builder).await
+    }
+}
+
+#[derive(Debug)]
+pub struct GetApiKeys<'a> {
+    client: &'a Client,
+}
+
+impl<'a> GetApiKeys<'a> {
+    #[doc(hidden)]
+    pub const fn new(client: &'a Client) -> Self {
+        Self { client }
+    }
+
+    /// Asynchronously executes the request and returns a list of [`ApiKey`] objects.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an [`Error`] if the request fails.
+    pub async fn exec(&self) -> Result<Vec<ApiKey>, Error> {
+        let builder = Builder::new(Route::GetApiKeys.into());
+        let res = self.client.request::<FractalList<ApiKey>>(builder).await?;
+
+        Ok(res.data.into_iter().map(|k| k.attributes).collect())
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+struct CreateApiKeyFields<'a> {
+    pub description: &'a str,
+    pub allowed_ips: Vec<&'a str>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ApiKeyMeta {
+    secret_token: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct CreateApiKeyResponse {
+    attributes: ApiKey,
+    meta: ApiKeyMeta,
+}
+
+#[derive(Debug)]
+pub struct CreateApiKey<'a> {
+    client: &'a Client,
+    fields: CreateApiKeyFields<'a>,
+}
+
+impl<'a> CreateApiKey<'a> {
+    #[doc(hidden)]
+    pub fn new(client: &'a Client) -> Self {
+        Self {
+            client,
+            fields: Default::default(),
+        }
+    }
+
+    /// Sets the description for the API key.
+    #[must_use = "an API key must have a description"]
+    pub fn description(mut self, description: &'a str) -> Self {
+        self.fields.description = description;
+
+        self
+    }
+
+    /// Adds an IP address or CIDR range that is allowed to use the API key. By default, the key
+    /// can be used from any address.
+    pub fn allowed_ip(mut self, ip: &'a str) -> Self {
+        self.fields.allowed_ips.push(ip);
+
+        self
+    }
+
+    /// Asynchronously executes the request and returns the new [`ApiKey`] object along with its
+    /// secret token. The full key used to authenticate is the identifier followed by the secret
+    /// token; the panel only returns the secret token once, so it must be stored by the caller.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an [`Error`] if the request fails, a field does not satisfy a validation rule or
+    /// the account has reached the maximum number of API keys.
+    pub async fn exec(self) -> Result<(ApiKey, String), Error> {
+        let builder = Builder::new(Route::CreateApiKey.into()).json(self.fields);
+        let res = self.client.request::<CreateApiKeyResponse>(builder).await?;
+
+        Ok((res.attributes, res.meta.secret_token))
+    }
+}
+
+#[derive(Debug)]
+pub struct DeleteApiKey<'a> {
+    client: &'a Client,
+    identifier: String,
+}
+
+impl<'a> DeleteApiKey<'a> {
+    #[doc(hidden)]
+    pub fn new(client: &'a Client, identifier: &str) -> Self {
+        Self {
+            client,
+            identifier: identifier.to_string(),
+        }
+    }
+
+    /// Asynchronously executes the request.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an [`Error`] if the request fails or if the API key is not found.
+    pub async fn exec(self) -> Result<(), Error> {
+        let builder = Builder::new(
+            Route::DeleteApiKey {
+                identifier: self.identifier,
+            }
+            .into(),
+        );
+
+        self.client.request::<()>(builder).await
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct TwoFactorResponse {
+    data: TwoFactorData,
+}
+
+#[derive(Debug)]
+pub struct GetTwoFactor<'a> {
+    client: &'a Client,
+}
+
+impl<'a> GetTwoFactor<'a> {
+    #[doc(hidden)]
+    pub const fn new(client: &'a Client) -> Self {
+        Self { client }
+    }
+
+    /// Asynchronously executes the request and returns the [`TwoFactorData`] for setting up
+    /// two-factor authentication, including the QR code image data.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an [`Error`] if the request fails or if two-factor authentication is already
+    /// enabled on the account.
+    pub async fn exec(&self) -> Result<TwoFactorData, Error> {
+        let builder = Builder::new(Route::GetTwoFactor.into());
+        let res = self.client.request::<TwoFactorResponse>(builder).await?;
+
+        Ok(res.data)
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+struct EnableTwoFactorFields<'a> {
+    pub code: &'a str,
+    pub password: &'a str,
+}
+
+#[derive(Debug, Deserialize)]
+struct RecoveryTokens {
+    tokens: Vec<String>,
+}
+
+#[derive(Debug)]
+pub struct EnableTwoFactor<'a> {
+    client: &'a Client,
+    fields: EnableTwoFactorFields<'a>,
+}
+
+impl<'a> EnableTwoFactor<'a> {
+    #[doc(hidden)]
+    pub fn new(client: &'a Client) -> Self {
+        Self {
+            client,
+            fields: Default::default(),
+        }
+    }
+
+    /// Sets the one-time code generated from the two-factor secret.
+    #[must_use = "a code is required to enable two-factor authentication"]
+    pub fn code(mut self, code: &'a str) -> Self {
+        self.fields.code = code;
+
+        self
+    }
+
+    /// Sets the current password of the account, required to confirm the change.
+    #[must_use = "the current password is required to enable two-factor authentication"]
+    pub fn password(mut self, password: &'a str) -> Self {
+        self.fields.password = password;
+
+        self
+    }
+
+    /// Asynchronously executes the request and returns the recovery tokens for the account.
+    /// These are only returned once and can each be used in place of a code to log in.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an [`Error`] if the request fails or if the code or password is incorrect.
+    pub async fn exec(self) -> Result<Vec<String>, Error> {
+        let builder = Builder::new(Route::EnableTwoFactor.into()).json(self.fields);
+        let res = self
+            .client
+            .request::<FractalItem<RecoveryTokens>>(builder)
+            .await?;
+
+        Ok(res.attributes.tokens)
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+struct DisableTwoFactorFields<'a> {
+    pub password: &'a str,
+}
+
+#[derive(Debug)]
+pub struct DisableTwoFactor<'a> {
+    client: &'a Client,
+    fields: DisableTwoFactorFields<'a>,
+}
+
+impl<'a> DisableTwoFactor<'a> {
+    #[doc(hidden)]
+    pub fn new(client: &'a Client) -> Self {
+        Self {
+            client,
+            fields: Default::default(),
+        }
+    }
+
+    /// Sets the current password of the account, required to confirm the change.
+    #[must_use = "the current password is required to disable two-factor authentication"]
+    pub fn password(mut self, password: &'a str) -> Self {
+        self.fields.password = password;
+
+        self
+    }
+
+    /// Asynchronously executes the request.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an [`Error`] if the request fails or if the password is incorrect.
+    pub async fn exec(self) -> Result<(), Error> {
+        let builder = Builder::new(Route::DisableTwoFactor.into()).json(self.fields);
+
+        self.client.request::<()>(builder).await
+    }
+}
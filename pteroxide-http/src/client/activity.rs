@@ -0,0 +1,141 @@
+use pteroxide_models::client::ActivityLog;
+
+use crate::{
+    query::SortOrder,
+    response::{impl_paginate, Response},
+    routing::{Client as Route, Route as BaseRoute},
+    Client,
+};
+
+// both activity endpoints share the same query parameters and the actor include
+fn paginate<'a>(
+    client: &'a Client,
+    route: BaseRoute,
+    event: Option<&str>,
+    sort: Option<SortOrder>,
+    page: u32,
+    per_page: u32,
+) -> Response<'a, ActivityLog> {
+    let mut res = Response::new(&client.http, route)
+        .page(page)
+        .per_page(per_page)
+        .include("actor");
+
+    if let Some(e) = event {
+        res = res.param("filter[event]", e);
+    }
+    if let Some(s) = sort {
+        res = res.param("sort", &s.with_field("timestamp"));
+    }
+
+    res
+}
+
+#[derive(Debug)]
+pub struct GetAccountActivity<'a> {
+    client: &'a Client,
+    event: Option<&'a str>,
+    sort: Option<SortOrder>,
+    page: u32,
+    per_page: u32,
+}
+
+impl<'a> GetAccountActivity<'a> {
+    #[doc(hidden)]
+    pub const fn new(client: &'a Client) -> Self {
+        Self {
+            client,
+            event: None,
+            sort: None,
+            page: 1,
+            per_page: 50,
+        }
+    }
+
+    /// Filters the activity logs by event name. This is a partial match, so `auth` matches all
+    /// authentication events.
+    pub fn event(mut self, event: &'a str) -> Self {
+        self.event = Some(event);
+
+        self
+    }
+
+    /// Sets the [`SortOrder`] of the activity logs by timestamp. Defaults to the newest first.
+    pub fn sort(mut self, order: SortOrder) -> Self {
+        self.sort = Some(order);
+
+        self
+    }
+
+    /// Returns a paginated [`Response`] for the request, which can fetch individual pages, collect
+    /// all pages or lazily walk through them as a stream. The activity logs include their actor.
+    pub fn paginate(&self) -> Response<'a, ActivityLog> {
+        paginate(
+            self.client,
+            Route::GetAccountActivity.into(),
+            self.event,
+            self.sort,
+            self.page,
+            self.per_page,
+        )
+    }
+}
+
+impl_paginate!(GetAccountActivity, ActivityLog, "activity logs");
+
+#[derive(Debug)]
+pub struct GetServerActivity<'a> {
+    client: &'a Client,
+    id: String,
+    event: Option<&'a str>,
+    sort: Option<SortOrder>,
+    page: u32,
+    per_page: u32,
+}
+
+impl<'a> GetServerActivity<'a> {
+    #[doc(hidden)]
+    pub fn new(client: &'a Client, id: &str) -> Self {
+        Self {
+            client,
+            id: id.to_string(),
+            event: None,
+            sort: None,
+            page: 1,
+            per_page: 50,
+        }
+    }
+
+    /// Filters the activity logs by event name. This is a partial match, so `server:file`
+    /// matches all file events.
+    pub fn event(mut self, event: &'a str) -> Self {
+        self.event = Some(event);
+
+        self
+    }
+
+    /// Sets the [`SortOrder`] of the activity logs by timestamp. Defaults to the newest first.
+    pub fn sort(mut self, order: SortOrder) -> Self {
+        self.sort = Some(order);
+
+        self
+    }
+
+    /// Returns a paginated [`Response`] for the request, which can fetch individual pages, collect
+    /// all pages or lazily walk through them as a stream. The activity logs include their actor.
+    pub fn paginate(&self) -> Response<'a, ActivityLog> {
+        paginate(
+            self.client,
+            Route::GetServerActivity {
+                id: self.id.clone(),
+            }
+            .into(),
+            self.event,
+            self.sort,
+            self.page,
+            self.per_page,
+        )
+    }
+}
+
+impl_paginate!(GetServerActivity, ActivityLog, "activity logs");
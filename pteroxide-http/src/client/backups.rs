@@ -0,0 +1,322 @@
+use pteroxide_models::{
+    client::{Backup, SignedUrl},
+    fractal::FractalItem,
+};
+use serde::Serialize;
+
+use crate::{
+    response::{impl_paginate, Response},
+    routing::Client as Route,
+    Builder, Client, Error,
+};
+
+#[derive(Debug)]
+pub struct GetBackups<'a> {
+    client: &'a Client,
+    id: String,
+    page: u32,
+    per_page: u32,
+}
+
+impl<'a> GetBackups<'a> {
+    #[doc(hidden)]
+    pub fn new(client: &'a Client, id: &str) -> Self {
+        Self {
+            client,
+            id: id.to_string(),
+            page: 1,
+            per_page: 50,
+        }
+    }
+
+    /// Returns a paginated [`Response`] for the request, which can fetch individual pages, collect
+    /// all pages or lazily walk through them as a stream.
+    pub fn paginate(&self) -> Response<'a, Backup> {
+        Response::new(
+            &self.client.http,
+            Route::GetBackups {
+                id: self.id.clone(),
+            }
+            .into(),
+        )
+        .page(self.page)
+        .per_page(self.per_page)
+    }
+}
+
+impl_paginate!(GetBackups, Backup, "backups");
+
+#[derive(Debug)]
+pub struct GetBackup<'a> {
+    client: &'a Client,
+    id: String,
+    backup: String,
+}
+
+impl<'a> GetBackup<'a> {
+    #[doc(hidden)]
+    pub fn new(client: &'a Client, id: &str, backup: &str) -> Self {
+        Self {
+            client,
+            id: id.to_string(),
+            backup: backup.to_string(),
+        }
+    }
+
+    /// Asynchronously executes the request and returns a [`Backup`] object.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an [`Error`] if the request fails or if the backup is not found.
+    pub async fn exec(&self) -> Result<Backup, Error> {
+        let builder = Builder::new(
+            Route::GetBackup {
+                id: self.id.clone(),
+                backup: self.backup.clone(),
+            }
+            .into(),
+        );
+        let res = self.client.request::<FractalItem<Backup>>(builder).await?;
+
+        Ok(res.attributes)
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+struct CreateBackupFields<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<&'a str>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(serialize_with = "serialize_ignored")]
+    pub ignored: Vec<&'a str>,
+    pub is_locked: bool,
+}
+
+// the panel expects the ignored files as a single newline-separated string
+fn serialize_ignored<S>(value: &[&str], serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    serializer.serialize_str(&value.join("\n"))
+}
+
+#[derive(Debug)]
+pub struct CreateBackup<'a> {
+    client: &'a Client,
+    id: String,
+    fields: CreateBackupFields<'a>,
+}
+
+impl<'a> CreateBackup<'a> {
+    #[doc(hidden)]
+    pub fn new(client: &'a Client, id: &str) -> Self {
+        Self {
+            client,
+            id: id.to_string(),
+            fields: Default::default(),
+        }
+    }
+
+    /// Sets the name of the backup. Defaults to [`None`] - the panel generates a name from the
+    /// current date and time.
+    pub fn name(mut self, name: &'a str) -> Self {
+        self.fields.name = Some(name);
+
+        self
+    }
+
+    /// Adds a file or pattern to exclude from the backup, in the same format as a `.gitignore`
+    /// file.
+    pub fn ignore(mut self, pattern: &'a str) -> Self {
+        self.fields.ignored.push(pattern);
+
+        self
+    }
+
+    /// Whether the backup should be locked, preventing it from being deleted until it is
+    /// unlocked. Defaults to `false`.
+    pub fn locked(mut self, value: bool) -> Self {
+        self.fields.is_locked = value;
+
+        self
+    }
+
+    /// Asynchronously executes the request and returns the new [`Backup`] object. Note that the
+    /// backup is created in the background, so it is not complete when this returns.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an [`Error`] if the request fails or if the server has reached its backup limit.
+    pub async fn exec(self) -> Result<Backup, Error> {
+        let builder = Builder::new(Route::CreateBackup { id: self.id }.into()).json(self.fields);
+        let res = self.client.request::<FractalItem<Backup>>(builder).await?;
+
+        Ok(res.attributes)
+    }
+}
+
+#[derive(Debug)]
+pub struct GetBackupDownload<'a> {
+    client: &'a Client,
+    id: String,
+    backup: String,
+}
+
+impl<'a> GetBackupDownload<'a> {
+    #[doc(hidden)]
+    pub fn new(client: &'a Client, id: &str, backup: &str) -> Self {
+        Self {
+            client,
+            id: id.to_string(),
+            backup: backup.to_string(),
+        }
+    }
+
+    /// Asynchronously executes the request and returns a signed URL for downloading the backup
+    /// archive. The URL expires after 15 minutes.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an [`Error`] if the request fails, if the backup is not found or if it is not
+    /// complete yet.
+    pub async fn exec(&self) -> Result<String, Error> {
+        let builder = Builder::new(
+            Route::GetBackupDownload {
+                id: self.id.clone(),
+                backup: self.backup.clone(),
+            }
+            .into(),
+        );
+        let res = self
+            .client
+            .request::<FractalItem<SignedUrl>>(builder)
+            .await?;
+
+        Ok(res.attributes.url)
+    }
+}
+
+#[derive(Debug)]
+pub struct LockBackup<'a> {
+    client: &'a Client,
+    id: String,
+    backup: String,
+}
+
+impl<'a> LockBackup<'a> {
+    #[doc(hidden)]
+    pub fn new(client: &'a Client, id: &str, backup: &str) -> Self {
+        Self {
+            client,
+            id: id.to_string(),
+            backup: backup.to_string(),
+        }
+    }
+
+    /// Asynchronously executes the request and returns the updated [`Backup`] object. This
+    /// toggles the lock, so a locked backup is unlocked and vice versa.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an [`Error`] if the request fails or if the backup is not found.
+    pub async fn exec(self) -> Result<Backup, Error> {
+        let builder = Builder::new(
+            Route::LockBackup {
+                id: self.id,
+                backup: self.backup,
+            }
+            .into(),
+        );
+        let res = self.client.request::<FractalItem<Backup>>(builder).await?;
+
+        Ok(res.attributes)
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+struct RestoreBackupFields {
+    pub truncate: bool,
+}
+
+#[derive(Debug)]
+pub struct RestoreBackup<'a> {
+    client: &'a Client,
+    id: String,
+    backup: String,
+    fields: RestoreBackupFields,
+}
+
+impl<'a> RestoreBackup<'a> {
+    #[doc(hidden)]
+    pub fn new(client: &'a Client, id: &str, backup: &str) -> Self {
+        Self {
+            client,
+            id: id.to_string(),
+            backup: backup.to_string(),
+            fields: Default::default(),
+        }
+    }
+
+    /// Whether all files on the server should be deleted before the backup is restored.
+    /// Defaults to `false`.
+    pub fn truncate(mut self, value: bool) -> Self {
+        self.fields.truncate = value;
+
+        self
+    }
+
+    /// Asynchronously executes the request. Note that the backup is restored in the background,
+    /// so it is not complete when this returns.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an [`Error`] if the request fails, if the backup is not found or is not complete,
+    /// or if the server is not in a state that allows restoring.
+    pub async fn exec(self) -> Result<(), Error> {
+        let builder = Builder::new(
+            Route::RestoreBackup {
+                id: self.id,
+                backup: self.backup,
+            }
+            .into(),
+        )
+        .json(self.fields);
+
+        self.client.request::<()>(builder).await
+    }
+}
+
+#[derive(Debug)]
+pub struct DeleteBackup<'a> {
+    client: &'a Client,
+    id: String,
+    backup: String,
+}
+
+impl<'a> DeleteBackup<'a> {
+    #[doc(hidden)]
+    pub fn new(client: &'a Client, id: &str, backup: &str) -> Self {
+        Self {
+            client,
+            id: id.to_string(),
+            backup: backup.to_string(),
+        }
+    }
+
+    /// Asynchronously executes the request.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an [`Error`] if the request fails, if the backup is not found or if it is locked.
+    pub async fn exec(self) -> Result<(), Error> {
+        let builder = Builder::new(
+            Route::DeleteBackup {
+                id: self.id,
+                backup: self.backup,
+            }
+            .into(),
+        );
+
+        self.client.request::<()>(builder).await
+    }
+}
@@ -0,0 +1,31 @@
+use super::{Client, DEFAULT_USER_AGENT};
+use crate::{
+    http::{impl_http_builder, HttpBuilder},
+    Error,
+};
+
+/// A builder for configuring a [`Client`] interface, including the HTTP client, timeouts and
+/// headers sent with every request.
+#[derive(Debug)]
+pub struct ClientBuilder(HttpBuilder);
+
+impl ClientBuilder {
+    /// Creates a new builder with the given API credentials.
+    pub fn new(url: String, key: String) -> Self {
+        Self(HttpBuilder::new(url, key, DEFAULT_USER_AGENT))
+    }
+
+    /// Builds the [`Client`] interface.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an [`Error`] if the user agent is not a valid header value or if the HTTP client
+    /// fails to be built.
+    pub fn build(self) -> Result<Client, Error> {
+        Ok(Client {
+            http: self.0.build()?,
+        })
+    }
+}
+
+impl_http_builder!(ClientBuilder, "Pteroxide HTTP Client");
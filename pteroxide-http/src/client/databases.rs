@@ -0,0 +1,191 @@
+use pteroxide_models::{
+    client::ServerDatabase,
+    fractal::{FractalItem, FractalList},
+};
+use serde::Serialize;
+
+use crate::{routing::Client as Route, Builder, Client, Error};
+
+#[derive(Debug)]
+pub struct GetDatabases<'a> {
+    client: &'a Client,
+    id: String,
+    with_password: bool,
+}
+
+impl<'a> GetDatabases<'a> {
+    #[doc(hidden)]
+    pub fn new(client: &'a Client, id: &str) -> Self {
+        Self {
+            client,
+            id: id.to_string(),
+            with_password: false,
+        }
+    }
+
+    /// Include the password of each database in the database [`relationships`]. This requires
+    /// the `database.view_password` permission.
+    ///
+    /// [`relationships`]: pteroxide_models::client::ServerDatabaseRelations
+    pub fn with_password(mut self, value: bool) -> Self {
+        self.with_password = value;
+
+        self
+    }
+
+    /// Asynchronously executes the request and returns a list of [`ServerDatabase`] objects.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an [`Error`] if the request fails or if the server is not found.
+    pub async fn exec(&self) -> Result<Vec<ServerDatabase>, Error> {
+        let mut builder = Builder::new(
+            Route::GetDatabases {
+                id: self.id.clone(),
+            }
+            .into(),
+        );
+        if self.with_password {
+            builder = builder.include("password");
+        }
+
+        let res = self
+            .client
+            .request::<FractalList<ServerDatabase>>(builder)
+            .await?;
+
+        Ok(res.data.into_iter().map(|d| d.attributes).collect())
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct CreateDatabaseFields<'a> {
+    pub database: &'a str,
+    pub remote: &'a str,
+}
+
+#[derive(Debug)]
+pub struct CreateDatabase<'a> {
+    client: &'a Client,
+    id: String,
+    fields: CreateDatabaseFields<'a>,
+}
+
+impl<'a> CreateDatabase<'a> {
+    #[doc(hidden)]
+    pub fn new(client: &'a Client, id: &str, database: &'a str) -> Self {
+        Self {
+            client,
+            id: id.to_string(),
+            fields: CreateDatabaseFields {
+                database,
+                remote: "%",
+            },
+        }
+    }
+
+    /// Sets the IP addresses allowed to connect to the database, where `%` is a wildcard.
+    /// Defaults to `%` (any address).
+    pub fn remote(mut self, remote: &'a str) -> Self {
+        self.fields.remote = remote;
+
+        self
+    }
+
+    /// Asynchronously executes the request and returns the new [`ServerDatabase`] object,
+    /// including its password in the [`relationships`]. Note that the panel prefixes the
+    /// database name with the server ID.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an [`Error`] if the request fails, if the server has reached its database limit or
+    /// a field does not satisfy a validation rule.
+    ///
+    /// [`relationships`]: pteroxide_models::client::ServerDatabaseRelations
+    pub async fn exec(self) -> Result<ServerDatabase, Error> {
+        let builder = Builder::new(Route::CreateDatabase { id: self.id }.into()).json(self.fields);
+        let res = self
+            .client
+            .request::<FractalItem<ServerDatabase>>(builder)
+            .await?;
+
+        Ok(res.attributes)
+    }
+}
+
+#[derive(Debug)]
+pub struct RotateDatabasePassword<'a> {
+    client: &'a Client,
+    id: String,
+    database: String,
+}
+
+impl<'a> RotateDatabasePassword<'a> {
+    #[doc(hidden)]
+    pub fn new(client: &'a Client, id: &str, database: &str) -> Self {
+        Self {
+            client,
+            id: id.to_string(),
+            database: database.to_string(),
+        }
+    }
+
+    /// Asynchronously executes the request and returns the updated [`ServerDatabase`] object,
+    /// including the new password in the [`relationships`].
+    ///
+    /// ## Errors
+    ///
+    /// Returns an [`Error`] if the request fails or if the database is not found.
+    ///
+    /// [`relationships`]: pteroxide_models::client::ServerDatabaseRelations
+    pub async fn exec(self) -> Result<ServerDatabase, Error> {
+        let builder = Builder::new(
+            Route::RotateDatabasePassword {
+                id: self.id,
+                database: self.database,
+            }
+            .into(),
+        );
+        let res = self
+            .client
+            .request::<FractalItem<ServerDatabase>>(builder)
+            .await?;
+
+        Ok(res.attributes)
+    }
+}
+
+#[derive(Debug)]
+pub struct DeleteDatabase<'a> {
+    client: &'a Client,
+    id: String,
+    database: String,
+}
+
+impl<'a> DeleteDatabase<'a> {
+    #[doc(hidden)]
+    pub fn new(client: &'a Client, id: &str, database: &str) -> Self {
+        Self {
+            client,
+            id: id.to_string(),
+            database: database.to_string(),
+        }
+    }
+
+    /// Asynchronously executes the request.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an [`Error`] if the request fails or if the database is not found.
+    pub async fn exec(self) -> Result<(), Error> {
+        let builder = Builder::new(
+            Route::DeleteDatabase {
+                id: self.id,
+                database: self.database,
+            }
+            .into(),
+        );
+
+        self.client.request::<()>(builder).await
+    }
+}
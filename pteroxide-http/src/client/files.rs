@@ -0,0 +1,637 @@
+use hyper::body::Bytes;
+use pteroxide_models::{
+    client::{FileObject, SignedUrl},
+    fractal::{FractalItem, FractalList},
+};
+use serde::Serialize;
+
+use crate::{routing::Client as Route, Builder, Client, Error};
+
+#[derive(Debug)]
+pub struct ListFiles<'a> {
+    client: &'a Client,
+    id: String,
+    directory: &'a str,
+}
+
+impl<'a> ListFiles<'a> {
+    #[doc(hidden)]
+    pub fn new(client: &'a Client, id: &str) -> Self {
+        Self {
+            client,
+            id: id.to_string(),
+            directory: "/",
+        }
+    }
+
+    /// Sets the directory to list the files of. Defaults to `/` - the server root.
+    pub fn directory(mut self, directory: &'a str) -> Self {
+        self.directory = directory;
+
+        self
+    }
+
+    /// Asynchronously executes the request and returns a list of [`FileObject`]s in the
+    /// directory.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an [`Error`] if the request fails or if the directory is not found.
+    pub async fn exec(&self) -> Result<Vec<FileObject>, Error> {
+        let builder = Builder::new(
+            Route::ListFiles {
+                id: self.id.clone(),
+            }
+            .into(),
+        )
+        .param("directory", self.directory);
+        let res = self
+            .client
+            .request::<FractalList<FileObject>>(builder)
+            .await?;
+
+        Ok(res.data.into_iter().map(|f| f.attributes).collect())
+    }
+}
+
+#[derive(Debug)]
+pub struct GetFileContents<'a> {
+    client: &'a Client,
+    id: String,
+    file: &'a str,
+}
+
+impl<'a> GetFileContents<'a> {
+    #[doc(hidden)]
+    pub fn new(client: &'a Client, id: &str, file: &'a str) -> Self {
+        Self {
+            client,
+            id: id.to_string(),
+            file,
+        }
+    }
+
+    /// Asynchronously executes the request and returns the raw contents of the file.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an [`Error`] if the request fails, if the file is not found or if the file is too
+    /// large to be read by the node.
+    pub async fn exec(&self) -> Result<Bytes, Error> {
+        let builder = Builder::new(
+            Route::GetFileContents {
+                id: self.id.clone(),
+            }
+            .into(),
+        )
+        .param("file", self.file);
+
+        self.client.http.request_raw(builder).await
+    }
+}
+
+#[derive(Debug)]
+pub struct GetFileDownload<'a> {
+    client: &'a Client,
+    id: String,
+    file: &'a str,
+}
+
+impl<'a> GetFileDownload<'a> {
+    #[doc(hidden)]
+    pub fn new(client: &'a Client, id: &str, file: &'a str) -> Self {
+        Self {
+            client,
+            id: id.to_string(),
+            file,
+        }
+    }
+
+    /// Asynchronously executes the request and returns a signed URL for downloading the file
+    /// directly from the node. The URL expires after 15 minutes.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an [`Error`] if the request fails or if the file is not found.
+    pub async fn exec(&self) -> Result<String, Error> {
+        let builder = Builder::new(
+            Route::GetFileDownload {
+                id: self.id.clone(),
+            }
+            .into(),
+        )
+        .param("file", self.file);
+        let res = self
+            .client
+            .request::<FractalItem<SignedUrl>>(builder)
+            .await?;
+
+        Ok(res.attributes.url)
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct RenameFile<'a> {
+    pub from: &'a str,
+    pub to: &'a str,
+}
+
+#[derive(Debug, Serialize)]
+struct RenameFilesFields<'a> {
+    pub root: &'a str,
+    pub files: Vec<RenameFile<'a>>,
+}
+
+#[derive(Debug)]
+pub struct RenameFiles<'a> {
+    client: &'a Client,
+    id: String,
+    fields: RenameFilesFields<'a>,
+}
+
+impl<'a> RenameFiles<'a> {
+    #[doc(hidden)]
+    pub fn new(client: &'a Client, id: &str) -> Self {
+        Self {
+            client,
+            id: id.to_string(),
+            fields: RenameFilesFields {
+                root: "/",
+                files: Vec::new(),
+            },
+        }
+    }
+
+    /// Sets the directory the file paths are relative to. Defaults to `/` - the server root.
+    pub fn root(mut self, root: &'a str) -> Self {
+        self.fields.root = root;
+
+        self
+    }
+
+    /// Adds a file to rename or move from one path to another.
+    #[must_use = "at least one file must be set to rename"]
+    pub fn file(mut self, from: &'a str, to: &'a str) -> Self {
+        self.fields.files.push(RenameFile { from, to });
+
+        self
+    }
+
+    /// Asynchronously executes the request.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an [`Error`] if the request fails or if a file is not found.
+    pub async fn exec(self) -> Result<(), Error> {
+        let builder = Builder::new(Route::RenameFiles { id: self.id }.into()).json(self.fields);
+
+        self.client.request::<()>(builder).await
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct CopyFileFields<'a> {
+    pub location: &'a str,
+}
+
+#[derive(Debug)]
+pub struct CopyFile<'a> {
+    client: &'a Client,
+    id: String,
+    fields: CopyFileFields<'a>,
+}
+
+impl<'a> CopyFile<'a> {
+    #[doc(hidden)]
+    pub fn new(client: &'a Client, id: &str, location: &'a str) -> Self {
+        Self {
+            client,
+            id: id.to_string(),
+            fields: CopyFileFields { location },
+        }
+    }
+
+    /// Asynchronously executes the request. The copy is created in the same directory as the
+    /// file, with `copy` appended to its name.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an [`Error`] if the request fails or if the file is not found.
+    pub async fn exec(self) -> Result<(), Error> {
+        let builder = Builder::new(Route::CopyFile { id: self.id }.into()).json(self.fields);
+
+        self.client.request::<()>(builder).await
+    }
+}
+
+#[derive(Debug)]
+pub struct WriteFile<'a> {
+    client: &'a Client,
+    id: String,
+    file: &'a str,
+    content: Bytes,
+}
+
+impl<'a> WriteFile<'a> {
+    #[doc(hidden)]
+    pub fn new(client: &'a Client, id: &str, file: &'a str) -> Self {
+        Self {
+            client,
+            id: id.to_string(),
+            file,
+            content: Bytes::new(),
+        }
+    }
+
+    /// Sets the content to write to the file. This replaces the existing content of the file.
+    #[must_use = "the file is emptied if no content is set"]
+    pub fn content<T>(mut self, content: T) -> Self
+    where
+        Bytes: From<T>,
+    {
+        self.content = Bytes::from(content);
+
+        self
+    }
+
+    /// Asynchronously executes the request. The file is created if it does not exist.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an [`Error`] if the request fails.
+    pub async fn exec(self) -> Result<(), Error> {
+        let builder = Builder::new(Route::WriteFile { id: self.id }.into())
+            .param("file", self.file)
+            .content_type("text/plain")
+            .body(self.content);
+
+        self.client.request::<()>(builder).await
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct FilesFields<'a> {
+    pub root: &'a str,
+    pub files: Vec<&'a str>,
+}
+
+#[derive(Debug)]
+pub struct CompressFiles<'a> {
+    client: &'a Client,
+    id: String,
+    fields: FilesFields<'a>,
+}
+
+impl<'a> CompressFiles<'a> {
+    #[doc(hidden)]
+    pub fn new(client: &'a Client, id: &str) -> Self {
+        Self {
+            client,
+            id: id.to_string(),
+            fields: FilesFields {
+                root: "/",
+                files: Vec::new(),
+            },
+        }
+    }
+
+    /// Sets the directory the file paths are relative to. This is also where the archive is
+    /// created. Defaults to `/` - the server root.
+    pub fn root(mut self, root: &'a str) -> Self {
+        self.fields.root = root;
+
+        self
+    }
+
+    /// Adds a file or directory to include in the archive.
+    #[must_use = "at least one file must be set to compress"]
+    pub fn file(mut self, file: &'a str) -> Self {
+        self.fields.files.push(file);
+
+        self
+    }
+
+    /// Asynchronously executes the request and returns the [`FileObject`] of the new archive.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an [`Error`] if the request fails, if a file is not found or if the server does
+    /// not have enough disk space for the archive.
+    pub async fn exec(self) -> Result<FileObject, Error> {
+        let builder = Builder::new(Route::CompressFiles { id: self.id }.into()).json(self.fields);
+        let res = self
+            .client
+            .request::<FractalItem<FileObject>>(builder)
+            .await?;
+
+        Ok(res.attributes)
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct DecompressFileFields<'a> {
+    pub root: &'a str,
+    pub file: &'a str,
+}
+
+#[derive(Debug)]
+pub struct DecompressFile<'a> {
+    client: &'a Client,
+    id: String,
+    fields: DecompressFileFields<'a>,
+}
+
+impl<'a> DecompressFile<'a> {
+    #[doc(hidden)]
+    pub fn new(client: &'a Client, id: &str, file: &'a str) -> Self {
+        Self {
+            client,
+            id: id.to_string(),
+            fields: DecompressFileFields { root: "/", file },
+        }
+    }
+
+    /// Sets the directory the archive path is relative to. This is also where the archive is
+    /// extracted. Defaults to `/` - the server root.
+    pub fn root(mut self, root: &'a str) -> Self {
+        self.fields.root = root;
+
+        self
+    }
+
+    /// Asynchronously executes the request.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an [`Error`] if the request fails, if the archive is not found or is not a
+    /// supported format, or if the server does not have enough disk space for the contents.
+    pub async fn exec(self) -> Result<(), Error> {
+        let builder = Builder::new(Route::DecompressFile { id: self.id }.into()).json(self.fields);
+
+        self.client.request::<()>(builder).await
+    }
+}
+
+#[derive(Debug)]
+pub struct DeleteFiles<'a> {
+    client: &'a Client,
+    id: String,
+    fields: FilesFields<'a>,
+}
+
+impl<'a> DeleteFiles<'a> {
+    #[doc(hidden)]
+    pub fn new(client: &'a Client, id: &str) -> Self {
+        Self {
+            client,
+            id: id.to_string(),
+            fields: FilesFields {
+                root: "/",
+                files: Vec::new(),
+            },
+        }
+    }
+
+    /// Sets the directory the file paths are relative to. Defaults to `/` - the server root.
+    pub fn root(mut self, root: &'a str) -> Self {
+        self.fields.root = root;
+
+        self
+    }
+
+    /// Adds a file or directory to delete. Directories are deleted with all their contents.
+    #[must_use = "at least one file must be set to delete"]
+    pub fn file(mut self, file: &'a str) -> Self {
+        self.fields.files.push(file);
+
+        self
+    }
+
+    /// Asynchronously executes the request.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an [`Error`] if the request fails.
+    pub async fn exec(self) -> Result<(), Error> {
+        let builder = Builder::new(Route::DeleteFiles { id: self.id }.into()).json(self.fields);
+
+        self.client.request::<()>(builder).await
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct CreateFolderFields<'a> {
+    pub root: &'a str,
+    pub name: &'a str,
+}
+
+#[derive(Debug)]
+pub struct CreateFolder<'a> {
+    client: &'a Client,
+    id: String,
+    fields: CreateFolderFields<'a>,
+}
+
+impl<'a> CreateFolder<'a> {
+    #[doc(hidden)]
+    pub fn new(client: &'a Client, id: &str, name: &'a str) -> Self {
+        Self {
+            client,
+            id: id.to_string(),
+            fields: CreateFolderFields { root: "/", name },
+        }
+    }
+
+    /// Sets the directory to create the folder in. Defaults to `/` - the server root.
+    pub fn root(mut self, root: &'a str) -> Self {
+        self.fields.root = root;
+
+        self
+    }
+
+    /// Asynchronously executes the request.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an [`Error`] if the request fails.
+    pub async fn exec(self) -> Result<(), Error> {
+        let builder = Builder::new(Route::CreateFolder { id: self.id }.into()).json(self.fields);
+
+        self.client.request::<()>(builder).await
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct ChmodFile<'a> {
+    pub file: &'a str,
+    pub mode: String,
+}
+
+#[derive(Debug, Serialize)]
+struct ChmodFilesFields<'a> {
+    pub root: &'a str,
+    pub files: Vec<ChmodFile<'a>>,
+}
+
+#[derive(Debug)]
+pub struct ChmodFiles<'a> {
+    client: &'a Client,
+    id: String,
+    fields: ChmodFilesFields<'a>,
+}
+
+impl<'a> ChmodFiles<'a> {
+    #[doc(hidden)]
+    pub fn new(client: &'a Client, id: &str) -> Self {
+        Self {
+            client,
+            id: id.to_string(),
+            fields: ChmodFilesFields {
+                root: "/",
+                files: Vec::new(),
+            },
+        }
+    }
+
+    /// Sets the directory the file paths are relative to. Defaults to `/` - the server root.
+    pub fn root(mut self, root: &'a str) -> Self {
+        self.fields.root = root;
+
+        self
+    }
+
+    /// Adds a file and the permission bits to set on it, such as `0o644`.
+    #[must_use = "at least one file must be set to change the mode of"]
+    pub fn file(mut self, file: &'a str, mode: u32) -> Self {
+        self.fields.files.push(ChmodFile {
+            file,
+            mode: format!("{:o}", mode),
+        });
+
+        self
+    }
+
+    /// Asynchronously executes the request.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an [`Error`] if the request fails or if a file is not found.
+    pub async fn exec(self) -> Result<(), Error> {
+        let builder = Builder::new(Route::ChmodFiles { id: self.id }.into()).json(self.fields);
+
+        self.client.request::<()>(builder).await
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+struct PullFileFields<'a> {
+    pub url: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub directory: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filename: Option<&'a str>,
+    pub use_header: bool,
+    pub foreground: bool,
+}
+
+#[derive(Debug)]
+pub struct PullFile<'a> {
+    client: &'a Client,
+    id: String,
+    fields: PullFileFields<'a>,
+}
+
+impl<'a> PullFile<'a> {
+    #[doc(hidden)]
+    pub fn new(client: &'a Client, id: &str, url: &'a str) -> Self {
+        Self {
+            client,
+            id: id.to_string(),
+            fields: PullFileFields {
+                url,
+                ..Default::default()
+            },
+        }
+    }
+
+    /// Sets the directory to save the file in. Defaults to [`None`] - the server root.
+    pub fn directory(mut self, directory: &'a str) -> Self {
+        self.fields.directory = Some(directory);
+
+        self
+    }
+
+    /// Sets the name to save the file as. Defaults to [`None`] - the name is taken from the URL.
+    pub fn filename(mut self, filename: &'a str) -> Self {
+        self.fields.filename = Some(filename);
+
+        self
+    }
+
+    /// Whether to take the file name from the `Content-Disposition` header of the response
+    /// instead of the URL. Defaults to `false`.
+    pub fn use_header(mut self, value: bool) -> Self {
+        self.fields.use_header = value;
+
+        self
+    }
+
+    /// Whether the request should wait for the download to finish. Defaults to `false` - the
+    /// download happens in the background on the node.
+    pub fn foreground(mut self, value: bool) -> Self {
+        self.fields.foreground = value;
+
+        self
+    }
+
+    /// Asynchronously executes the request.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an [`Error`] if the request fails, if the URL is invalid or if the server has
+    /// reached the maximum number of concurrent downloads.
+    pub async fn exec(self) -> Result<(), Error> {
+        let builder = Builder::new(Route::PullFile { id: self.id }.into()).json(self.fields);
+
+        self.client.request::<()>(builder).await
+    }
+}
+
+#[derive(Debug)]
+pub struct GetFileUpload<'a> {
+    client: &'a Client,
+    id: String,
+}
+
+impl<'a> GetFileUpload<'a> {
+    #[doc(hidden)]
+    pub fn new(client: &'a Client, id: &str) -> Self {
+        Self {
+            client,
+            id: id.to_string(),
+        }
+    }
+
+    /// Asynchronously executes the request and returns a signed URL for uploading files directly
+    /// to the node. Files are uploaded as `multipart/form-data` with the `files` field, and the
+    /// target directory can be set with the `directory` query parameter. The URL expires after
+    /// 15 minutes.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an [`Error`] if the request fails.
+    pub async fn exec(&self) -> Result<String, Error> {
+        let builder = Builder::new(
+            Route::GetFileUpload {
+                id: self.id.clone(),
+            }
+            .into(),
+        );
+        let res = self
+            .client
+            .request::<FractalItem<SignedUrl>>(builder)
+            .await?;
+
+        Ok(res.attributes.url)
+    }
+}
@@ -0,0 +1,583 @@
+#[cfg(feature = "native-tls")]
+use hyper::Client as HClient;
+#[cfg(feature = "native-tls")]
+use hyper_tls::HttpsConnector;
+use pteroxide_models::PowerSignal;
+use serde::Deserialize;
+
+#[cfg(feature = "websocket")]
+use self::websocket::ConnectWebSocket;
+use self::{
+    account::{
+        CreateApiKey, DeleteApiKey, DisableTwoFactor, EnableTwoFactor, GetAccount, GetApiKeys,
+        GetTwoFactor, UpdateEmail, UpdatePassword,
+    },
+    activity::{GetAccountActivity, GetServerActivity},
+    backups::{
+        CreateBackup, DeleteBackup, GetBackup, GetBackupDownload, GetBackups, LockBackup,
+        RestoreBackup,
+    },
+    builder::ClientBuilder,
+    databases::{CreateDatabase, DeleteDatabase, GetDatabases, RotateDatabasePassword},
+    files::{
+        ChmodFiles, CompressFiles, CopyFile, CreateFolder, DecompressFile, DeleteFiles,
+        GetFileContents, GetFileDownload, GetFileUpload, ListFiles, PullFile, RenameFiles,
+        WriteFile,
+    },
+    network::{
+        AssignAllocation, GetAllocations, SetAllocationNotes, SetPrimaryAllocation,
+        UnassignAllocation,
+    },
+    schedules::{
+        CreateSchedule, CreateTask, DeleteSchedule, DeleteTask, ExecuteSchedule, GetSchedule,
+        GetSchedules, UpdateSchedule, UpdateTask,
+    },
+    servers::{
+        GetServer, GetServerResources, GetServers, GetWebSocket, SendCommand, SendPowerSignal,
+    },
+    settings::{ReinstallServer, RenameServer, UpdateDockerImage},
+    startup::{GetStartup, UpdateStartupVariable},
+    subusers::{
+        CreateSubUser, DeleteSubUser, GetPermissions, GetSubUser, GetSubUsers, UpdateSubUser,
+    },
+};
+use super::{error::*, http::Http, ratelimit::RetryPolicy, transport::Transport, Builder};
+
+pub mod account;
+pub mod activity;
+pub mod backups;
+pub mod builder;
+pub mod databases;
+pub mod files;
+pub mod network;
+pub mod schedules;
+pub mod servers;
+pub mod settings;
+pub mod startup;
+pub mod subusers;
+#[cfg(feature = "websocket")]
+pub mod websocket;
+
+pub(crate) const DEFAULT_USER_AGENT: &str = "Pteroxide HTTP Client";
+
+/// The main interface for interacting with the client API.
+#[derive(Debug)]
+pub struct Client {
+    pub(crate) http: Http,
+}
+
+impl Client {
+    /// Constructs a new [`Client`] with the given API credentials, using a hyper client with a
+    /// [`native-tls`](hyper_tls) connector.
+    #[cfg(feature = "native-tls")]
+    pub fn new(url: String, key: String) -> Self {
+        let conn = HttpsConnector::new();
+
+        Self::with_transport(url, key, HClient::builder().build(conn))
+    }
+
+    /// Constructs a new [`Client`] with the given API credentials, sending requests through the
+    /// given [`Transport`].
+    pub fn with_transport<T>(url: String, key: String, transport: T) -> Self
+    where
+        T: Transport + 'static,
+    {
+        Self {
+            http: Http::new(url, key, Box::new(transport), DEFAULT_USER_AGENT),
+        }
+    }
+
+    /// Returns a [`ClientBuilder`] for configuring the interface with the given API credentials.
+    pub fn builder(url: String, key: String) -> ClientBuilder {
+        ClientBuilder::new(url, key)
+    }
+
+    /// Sets the [`RetryPolicy`] to use when a request is ratelimited and returns the interface.
+    /// By default, ratelimited requests are not retried and return a [`RatelimitError`].
+    ///
+    /// [`RatelimitError`]: ErrorKind::RatelimitError
+    pub fn retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.http.retry_policy = Some(policy);
+
+        self
+    }
+
+    /// Performs an API request using the [`Builder`] with the set fields. Returns a result with
+    /// the deserialized API response, if any.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// # use pteroxide_http::{routing::Client as Route, Builder, Client, Error};
+    /// # use pteroxide_models::{client::Account, fractal::FractalItem};
+    /// # async fn run(client: Client) -> Result<(), Error> {
+    /// let builder = Builder::new(Route::GetAccount.into());
+    /// let data = client.request::<FractalItem<Account>>(builder).await?;
+    /// println!("{:#?}", data.attributes);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// ## Errors
+    ///
+    /// Returns an [`Error`] if the request fails, if the request is ratelimited and cannot be
+    /// retried, or if the response fails to be deserialized.
+    pub async fn request<T>(&self, builder: Builder) -> Result<T, Error>
+    where
+        for<'de> T: Deserialize<'de>,
+    {
+        self.http.request(builder).await
+    }
+
+    /// Returns a request builder for getting the [`Account`] of the API key owner.
+    ///
+    /// [`Account`]: pteroxide_models::client::Account
+    pub const fn get_account(&self) -> GetAccount<'_> {
+        GetAccount::new(self)
+    }
+
+    /// Returns a request builder for updating the email of the account.
+    pub fn update_email(&self) -> UpdateEmail<'_> {
+        UpdateEmail::new(self)
+    }
+
+    /// Returns a request builder for updating the password of the account.
+    pub fn update_password(&self) -> UpdatePassword<'_> {
+        UpdatePassword::new(self)
+    }
+
+    /// Returns a request builder for getting a list of the account's [`ApiKey`]s.
+    ///
+    /// [`ApiKey`]: pteroxide_models::client::ApiKey
+    pub const fn get_api_keys(&self) -> GetApiKeys<'_> {
+        GetApiKeys::new(self)
+    }
+
+    /// Returns a request builder for creating an [`ApiKey`].
+    ///
+    /// [`ApiKey`]: pteroxide_models::client::ApiKey
+    pub fn create_api_key(&self) -> CreateApiKey<'_> {
+        CreateApiKey::new(self)
+    }
+
+    /// Returns a request builder for deleting an [`ApiKey`].
+    ///
+    /// [`ApiKey`]: pteroxide_models::client::ApiKey
+    pub fn delete_api_key(&self, identifier: &str) -> DeleteApiKey<'_> {
+        DeleteApiKey::new(self, identifier)
+    }
+
+    /// Returns a request builder for getting the [`TwoFactorData`] used to set up two-factor
+    /// authentication.
+    ///
+    /// [`TwoFactorData`]: pteroxide_models::client::TwoFactorData
+    pub const fn get_two_factor(&self) -> GetTwoFactor<'_> {
+        GetTwoFactor::new(self)
+    }
+
+    /// Returns a request builder for enabling two-factor authentication on the account.
+    pub fn enable_two_factor(&self) -> EnableTwoFactor<'_> {
+        EnableTwoFactor::new(self)
+    }
+
+    /// Returns a request builder for disabling two-factor authentication on the account.
+    pub fn disable_two_factor(&self) -> DisableTwoFactor<'_> {
+        DisableTwoFactor::new(self)
+    }
+
+    /// Returns a request builder for getting a list of [`Server`]s the user has access to.
+    ///
+    /// [`Server`]: pteroxide_models::client::Server
+    pub const fn get_servers(&self) -> GetServers<'_> {
+        GetServers::new(self)
+    }
+
+    /// Returns a request builder for getting a specified [`Server`] by its identifier or UUID.
+    ///
+    /// [`Server`]: pteroxide_models::client::Server
+    pub fn get_server(&self, id: &str) -> GetServer<'_> {
+        GetServer::new(self, id)
+    }
+
+    /// Returns a request builder for getting the current state and [`Resources`] usage of a
+    /// specified server.
+    ///
+    /// [`Resources`]: pteroxide_models::client::Resources
+    pub fn get_server_resources(&self, id: &str) -> GetServerResources<'_> {
+        GetServerResources::new(self, id)
+    }
+
+    /// Returns a request builder for sending a [`PowerSignal`] to a specified server.
+    pub fn send_power_signal(&self, id: &str, signal: PowerSignal) -> SendPowerSignal<'_> {
+        SendPowerSignal::new(self, id, signal)
+    }
+
+    /// Returns a request builder for sending a command to the console of a specified server.
+    pub fn send_command<'a>(&'a self, id: &str, command: &'a str) -> SendCommand<'a> {
+        SendCommand::new(self, id, command)
+    }
+
+    /// Returns a request builder for getting the [`WebSocketAuth`] credentials for connecting to
+    /// the websocket of a specified server.
+    ///
+    /// [`WebSocketAuth`]: pteroxide_models::client::WebSocketAuth
+    pub fn get_websocket(&self, id: &str) -> GetWebSocket<'_> {
+        GetWebSocket::new(self, id)
+    }
+
+    /// Returns a request builder for connecting to the websocket of a specified server, used for
+    /// receiving console output and events and sending commands.
+    #[cfg(feature = "websocket")]
+    pub fn connect_websocket(&self, id: &str) -> ConnectWebSocket<'_> {
+        ConnectWebSocket::new(self, id)
+    }
+
+    /// Returns a request builder for listing the [`FileObject`]s in a directory of a specified
+    /// server.
+    ///
+    /// [`FileObject`]: pteroxide_models::client::FileObject
+    pub fn list_files(&self, id: &str) -> ListFiles<'_> {
+        ListFiles::new(self, id)
+    }
+
+    /// Returns a request builder for getting the contents of a file on a specified server.
+    pub fn get_file_contents<'a>(&'a self, id: &str, file: &'a str) -> GetFileContents<'a> {
+        GetFileContents::new(self, id, file)
+    }
+
+    /// Returns a request builder for getting a download URL for a file on a specified server.
+    pub fn get_file_download<'a>(&'a self, id: &str, file: &'a str) -> GetFileDownload<'a> {
+        GetFileDownload::new(self, id, file)
+    }
+
+    /// Returns a request builder for renaming or moving files on a specified server.
+    pub fn rename_files(&self, id: &str) -> RenameFiles<'_> {
+        RenameFiles::new(self, id)
+    }
+
+    /// Returns a request builder for copying a file on a specified server.
+    pub fn copy_file<'a>(&'a self, id: &str, location: &'a str) -> CopyFile<'a> {
+        CopyFile::new(self, id, location)
+    }
+
+    /// Returns a request builder for writing the contents of a file on a specified server.
+    pub fn write_file<'a>(&'a self, id: &str, file: &'a str) -> WriteFile<'a> {
+        WriteFile::new(self, id, file)
+    }
+
+    /// Returns a request builder for compressing files into an archive on a specified server.
+    pub fn compress_files(&self, id: &str) -> CompressFiles<'_> {
+        CompressFiles::new(self, id)
+    }
+
+    /// Returns a request builder for decompressing an archive on a specified server.
+    pub fn decompress_file<'a>(&'a self, id: &str, file: &'a str) -> DecompressFile<'a> {
+        DecompressFile::new(self, id, file)
+    }
+
+    /// Returns a request builder for deleting files on a specified server.
+    pub fn delete_files(&self, id: &str) -> DeleteFiles<'_> {
+        DeleteFiles::new(self, id)
+    }
+
+    /// Returns a request builder for creating a folder on a specified server.
+    pub fn create_folder<'a>(&'a self, id: &str, name: &'a str) -> CreateFolder<'a> {
+        CreateFolder::new(self, id, name)
+    }
+
+    /// Returns a request builder for changing the permissions of files on a specified server.
+    pub fn chmod_files(&self, id: &str) -> ChmodFiles<'_> {
+        ChmodFiles::new(self, id)
+    }
+
+    /// Returns a request builder for downloading a file from a remote URL onto a specified
+    /// server.
+    pub fn pull_file<'a>(&'a self, id: &str, url: &'a str) -> PullFile<'a> {
+        PullFile::new(self, id, url)
+    }
+
+    /// Returns a request builder for getting an upload URL for files on a specified server.
+    pub fn get_file_upload(&self, id: &str) -> GetFileUpload<'_> {
+        GetFileUpload::new(self, id)
+    }
+
+    /// Returns a request builder for getting a list of [`Backup`]s of a specified server.
+    ///
+    /// [`Backup`]: pteroxide_models::client::Backup
+    pub fn get_backups(&self, id: &str) -> GetBackups<'_> {
+        GetBackups::new(self, id)
+    }
+
+    /// Returns a request builder for getting a specified [`Backup`] of a server.
+    ///
+    /// [`Backup`]: pteroxide_models::client::Backup
+    pub fn get_backup(&self, id: &str, backup: &str) -> GetBackup<'_> {
+        GetBackup::new(self, id, backup)
+    }
+
+    /// Returns a request builder for creating a [`Backup`] of a specified server.
+    ///
+    /// [`Backup`]: pteroxide_models::client::Backup
+    pub fn create_backup(&self, id: &str) -> CreateBackup<'_> {
+        CreateBackup::new(self, id)
+    }
+
+    /// Returns a request builder for getting a download URL for a [`Backup`] of a server.
+    ///
+    /// [`Backup`]: pteroxide_models::client::Backup
+    pub fn get_backup_download(&self, id: &str, backup: &str) -> GetBackupDownload<'_> {
+        GetBackupDownload::new(self, id, backup)
+    }
+
+    /// Returns a request builder for toggling the lock on a [`Backup`] of a server.
+    ///
+    /// [`Backup`]: pteroxide_models::client::Backup
+    pub fn lock_backup(&self, id: &str, backup: &str) -> LockBackup<'_> {
+        LockBackup::new(self, id, backup)
+    }
+
+    /// Returns a request builder for restoring a [`Backup`] of a server.
+    ///
+    /// [`Backup`]: pteroxide_models::client::Backup
+    pub fn restore_backup(&self, id: &str, backup: &str) -> RestoreBackup<'_> {
+        RestoreBackup::new(self, id, backup)
+    }
+
+    /// Returns a request builder for deleting a [`Backup`] of a server.
+    ///
+    /// [`Backup`]: pteroxide_models::client::Backup
+    pub fn delete_backup(&self, id: &str, backup: &str) -> DeleteBackup<'_> {
+        DeleteBackup::new(self, id, backup)
+    }
+
+    /// Returns a request builder for getting a list of [`Schedule`]s for a specified server.
+    ///
+    /// [`Schedule`]: pteroxide_models::client::Schedule
+    pub fn get_schedules(&self, id: &str) -> GetSchedules<'_> {
+        GetSchedules::new(self, id)
+    }
+
+    /// Returns a request builder for getting a specified [`Schedule`] of a server.
+    ///
+    /// [`Schedule`]: pteroxide_models::client::Schedule
+    pub fn get_schedule(&self, id: &str, schedule: i32) -> GetSchedule<'_> {
+        GetSchedule::new(self, id, schedule)
+    }
+
+    /// Returns a request builder for creating a [`Schedule`] for a specified server.
+    ///
+    /// [`Schedule`]: pteroxide_models::client::Schedule
+    pub fn create_schedule(&self, id: &str) -> CreateSchedule<'_> {
+        CreateSchedule::new(self, id)
+    }
+
+    /// Returns a request builder for updating a [`Schedule`] of a server.
+    ///
+    /// [`Schedule`]: pteroxide_models::client::Schedule
+    pub fn update_schedule(&self, id: &str, schedule: i32) -> UpdateSchedule<'_> {
+        UpdateSchedule::new(self, id, schedule)
+    }
+
+    /// Returns a request builder for running a [`Schedule`] of a server immediately.
+    ///
+    /// [`Schedule`]: pteroxide_models::client::Schedule
+    pub fn execute_schedule(&self, id: &str, schedule: i32) -> ExecuteSchedule<'_> {
+        ExecuteSchedule::new(self, id, schedule)
+    }
+
+    /// Returns a request builder for deleting a [`Schedule`] of a server.
+    ///
+    /// [`Schedule`]: pteroxide_models::client::Schedule
+    pub fn delete_schedule(&self, id: &str, schedule: i32) -> DeleteSchedule<'_> {
+        DeleteSchedule::new(self, id, schedule)
+    }
+
+    /// Returns a request builder for creating a [`Task`] for a schedule of a server.
+    ///
+    /// [`Task`]: pteroxide_models::client::Task
+    pub fn create_task(&self, id: &str, schedule: i32) -> CreateTask<'_> {
+        CreateTask::new(self, id, schedule)
+    }
+
+    /// Returns a request builder for updating a [`Task`] of a schedule.
+    ///
+    /// [`Task`]: pteroxide_models::client::Task
+    pub fn update_task(&self, id: &str, schedule: i32, task: i32) -> UpdateTask<'_> {
+        UpdateTask::new(self, id, schedule, task)
+    }
+
+    /// Returns a request builder for deleting a [`Task`] of a schedule.
+    ///
+    /// [`Task`]: pteroxide_models::client::Task
+    pub fn delete_task(&self, id: &str, schedule: i32, task: i32) -> DeleteTask<'_> {
+        DeleteTask::new(self, id, schedule, task)
+    }
+
+    /// Returns a request builder for getting the permission catalogue of the panel, describing
+    /// every [`Permission`] that can be given to a subuser.
+    ///
+    /// [`Permission`]: pteroxide_models::Permission
+    pub const fn get_permissions(&self) -> GetPermissions<'_> {
+        GetPermissions::new(self)
+    }
+
+    /// Returns a request builder for getting a list of [`SubUser`]s for a specified server.
+    ///
+    /// [`SubUser`]: pteroxide_models::client::SubUser
+    pub fn get_subusers(&self, id: &str) -> GetSubUsers<'_> {
+        GetSubUsers::new(self, id)
+    }
+
+    /// Returns a request builder for getting a specified [`SubUser`] of a server by the user's
+    /// UUID.
+    ///
+    /// [`SubUser`]: pteroxide_models::client::SubUser
+    pub fn get_subuser(&self, id: &str, user: &str) -> GetSubUser<'_> {
+        GetSubUser::new(self, id, user)
+    }
+
+    /// Returns a request builder for adding a [`SubUser`] to a specified server by their email.
+    ///
+    /// [`SubUser`]: pteroxide_models::client::SubUser
+    pub fn create_subuser<'a>(&'a self, id: &str, email: &'a str) -> CreateSubUser<'a> {
+        CreateSubUser::new(self, id, email)
+    }
+
+    /// Returns a request builder for updating the permissions of a [`SubUser`] of a server.
+    ///
+    /// [`SubUser`]: pteroxide_models::client::SubUser
+    pub fn update_subuser(&self, id: &str, user: &str) -> UpdateSubUser<'_> {
+        UpdateSubUser::new(self, id, user)
+    }
+
+    /// Returns a request builder for removing a [`SubUser`] from a server.
+    ///
+    /// [`SubUser`]: pteroxide_models::client::SubUser
+    pub fn delete_subuser(&self, id: &str, user: &str) -> DeleteSubUser<'_> {
+        DeleteSubUser::new(self, id, user)
+    }
+
+    /// Returns a request builder for getting a list of [`ServerDatabase`]s for a specified server.
+    ///
+    /// [`ServerDatabase`]: pteroxide_models::client::ServerDatabase
+    pub fn get_databases(&self, id: &str) -> GetDatabases<'_> {
+        GetDatabases::new(self, id)
+    }
+
+    /// Returns a request builder for creating a [`ServerDatabase`] for a specified server.
+    ///
+    /// [`ServerDatabase`]: pteroxide_models::client::ServerDatabase
+    pub fn create_database<'a>(&'a self, id: &str, database: &'a str) -> CreateDatabase<'a> {
+        CreateDatabase::new(self, id, database)
+    }
+
+    /// Returns a request builder for rotating the password of a [`ServerDatabase`] of a server.
+    ///
+    /// [`ServerDatabase`]: pteroxide_models::client::ServerDatabase
+    pub fn rotate_database_password(&self, id: &str, database: &str) -> RotateDatabasePassword<'_> {
+        RotateDatabasePassword::new(self, id, database)
+    }
+
+    /// Returns a request builder for deleting a [`ServerDatabase`] of a server.
+    ///
+    /// [`ServerDatabase`]: pteroxide_models::client::ServerDatabase
+    pub fn delete_database(&self, id: &str, database: &str) -> DeleteDatabase<'_> {
+        DeleteDatabase::new(self, id, database)
+    }
+
+    /// Returns a request builder for getting a list of network [`Allocation`]s for a specified
+    /// server.
+    ///
+    /// [`Allocation`]: pteroxide_models::client::Allocation
+    pub fn get_allocations(&self, id: &str) -> GetAllocations<'_> {
+        GetAllocations::new(self, id)
+    }
+
+    /// Returns a request builder for automatically assigning a new [`Allocation`] to a specified
+    /// server.
+    ///
+    /// [`Allocation`]: pteroxide_models::client::Allocation
+    pub fn assign_allocation(&self, id: &str) -> AssignAllocation<'_> {
+        AssignAllocation::new(self, id)
+    }
+
+    /// Returns a request builder for setting the notes of an [`Allocation`] of a server. Setting
+    /// the notes to [`None`] clears them.
+    ///
+    /// [`Allocation`]: pteroxide_models::client::Allocation
+    pub fn set_allocation_notes<'a>(
+        &'a self,
+        id: &str,
+        allocation: i32,
+        notes: Option<&'a str>,
+    ) -> SetAllocationNotes<'a> {
+        SetAllocationNotes::new(self, id, allocation, notes)
+    }
+
+    /// Returns a request builder for setting the primary [`Allocation`] of a server.
+    ///
+    /// [`Allocation`]: pteroxide_models::client::Allocation
+    pub fn set_primary_allocation(&self, id: &str, allocation: i32) -> SetPrimaryAllocation<'_> {
+        SetPrimaryAllocation::new(self, id, allocation)
+    }
+
+    /// Returns a request builder for unassigning an [`Allocation`] from a server.
+    ///
+    /// [`Allocation`]: pteroxide_models::client::Allocation
+    pub fn unassign_allocation(&self, id: &str, allocation: i32) -> UnassignAllocation<'_> {
+        UnassignAllocation::new(self, id, allocation)
+    }
+
+    /// Returns a request builder for renaming a specified server.
+    pub fn rename_server<'a>(&'a self, id: &str, name: &'a str) -> RenameServer<'a> {
+        RenameServer::new(self, id, name)
+    }
+
+    /// Returns a request builder for reinstalling a specified server.
+    pub fn reinstall_server(&self, id: &str) -> ReinstallServer<'_> {
+        ReinstallServer::new(self, id)
+    }
+
+    /// Returns a request builder for changing the docker image of a specified server. The image
+    /// must be one of the images in the server [`Startup`].
+    ///
+    /// [`Startup`]: pteroxide_models::client::Startup
+    pub fn update_docker_image<'a>(&'a self, id: &str, image: &'a str) -> UpdateDockerImage<'a> {
+        UpdateDockerImage::new(self, id, image)
+    }
+
+    /// Returns a request builder for getting the [`Startup`] configuration of a specified
+    /// server, including its variables.
+    ///
+    /// [`Startup`]: pteroxide_models::client::Startup
+    pub fn get_startup(&self, id: &str) -> GetStartup<'_> {
+        GetStartup::new(self, id)
+    }
+
+    /// Returns a request builder for setting the value of a [`StartupVariable`] of a server by
+    /// its environment variable name.
+    ///
+    /// [`StartupVariable`]: pteroxide_models::client::StartupVariable
+    pub fn update_startup_variable<'a>(
+        &'a self,
+        id: &str,
+        key: &'a str,
+        value: &'a str,
+    ) -> UpdateStartupVariable<'a> {
+        UpdateStartupVariable::new(self, id, key, value)
+    }
+
+    /// Returns a request builder for getting the [`ActivityLog`]s of the account.
+    ///
+    /// [`ActivityLog`]: pteroxide_models::client::ActivityLog
+    pub const fn get_account_activity(&self) -> GetAccountActivity<'_> {
+        GetAccountActivity::new(self)
+    }
+
+    /// Returns a request builder for getting the [`ActivityLog`]s of a specified server.
+    ///
+    /// [`ActivityLog`]: pteroxide_models::client::ActivityLog
+    pub fn get_server_activity(&self, id: &str) -> GetServerActivity<'_> {
+        GetServerActivity::new(self, id)
+    }
+}
@@ -0,0 +1,201 @@
+use pteroxide_models::{
+    client::Allocation,
+    fractal::{FractalItem, FractalList},
+};
+use serde::Serialize;
+
+use crate::{routing::Client as Route, Builder, Client, Error};
+
+#[derive(Debug)]
+pub struct GetAllocations<'a> {
+    client: &'a Client,
+    id: String,
+}
+
+impl<'a> GetAllocations<'a> {
+    #[doc(hidden)]
+    pub fn new(client: &'a Client, id: &str) -> Self {
+        Self {
+            client,
+            id: id.to_string(),
+        }
+    }
+
+    /// Asynchronously executes the request and returns a list of [`Allocation`] objects.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an [`Error`] if the request fails or if the server is not found.
+    pub async fn exec(&self) -> Result<Vec<Allocation>, Error> {
+        let builder = Builder::new(
+            Route::GetAllocations {
+                id: self.id.clone(),
+            }
+            .into(),
+        );
+        let res = self
+            .client
+            .request::<FractalList<Allocation>>(builder)
+            .await?;
+
+        Ok(res.data.into_iter().map(|a| a.attributes).collect())
+    }
+}
+
+#[derive(Debug)]
+pub struct AssignAllocation<'a> {
+    client: &'a Client,
+    id: String,
+}
+
+impl<'a> AssignAllocation<'a> {
+    #[doc(hidden)]
+    pub fn new(client: &'a Client, id: &str) -> Self {
+        Self {
+            client,
+            id: id.to_string(),
+        }
+    }
+
+    /// Asynchronously executes the request and returns the [`Allocation`] object that was
+    /// assigned to the server.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an [`Error`] if the request fails, if automatic allocation assignment is disabled
+    /// on the panel, if the server has reached its allocation limit or if there are no free
+    /// allocations left in the configured port range.
+    pub async fn exec(self) -> Result<Allocation, Error> {
+        let builder = Builder::new(Route::AssignAllocation { id: self.id }.into());
+        let res = self
+            .client
+            .request::<FractalItem<Allocation>>(builder)
+            .await?;
+
+        Ok(res.attributes)
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct SetAllocationNotesFields<'a> {
+    pub notes: Option<&'a str>,
+}
+
+#[derive(Debug)]
+pub struct SetAllocationNotes<'a> {
+    client: &'a Client,
+    id: String,
+    allocation: i32,
+    fields: SetAllocationNotesFields<'a>,
+}
+
+impl<'a> SetAllocationNotes<'a> {
+    #[doc(hidden)]
+    pub fn new(client: &'a Client, id: &str, allocation: i32, notes: Option<&'a str>) -> Self {
+        Self {
+            client,
+            id: id.to_string(),
+            allocation,
+            fields: SetAllocationNotesFields { notes },
+        }
+    }
+
+    /// Asynchronously executes the request and returns the updated [`Allocation`] object.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an [`Error`] if the request fails, if the allocation is not found or if the notes
+    /// are longer than 256 characters.
+    pub async fn exec(self) -> Result<Allocation, Error> {
+        let builder = Builder::new(
+            Route::SetAllocationNotes {
+                id: self.id,
+                allocation: self.allocation,
+            }
+            .into(),
+        )
+        .json(self.fields);
+        let res = self
+            .client
+            .request::<FractalItem<Allocation>>(builder)
+            .await?;
+
+        Ok(res.attributes)
+    }
+}
+
+#[derive(Debug)]
+pub struct SetPrimaryAllocation<'a> {
+    client: &'a Client,
+    id: String,
+    allocation: i32,
+}
+
+impl<'a> SetPrimaryAllocation<'a> {
+    #[doc(hidden)]
+    pub fn new(client: &'a Client, id: &str, allocation: i32) -> Self {
+        Self {
+            client,
+            id: id.to_string(),
+            allocation,
+        }
+    }
+
+    /// Asynchronously executes the request and returns the updated [`Allocation`] object. Note
+    /// that the server must be restarted for the change to take effect.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an [`Error`] if the request fails or if the allocation is not found.
+    pub async fn exec(self) -> Result<Allocation, Error> {
+        let builder = Builder::new(
+            Route::SetPrimaryAllocation {
+                id: self.id,
+                allocation: self.allocation,
+            }
+            .into(),
+        );
+        let res = self
+            .client
+            .request::<FractalItem<Allocation>>(builder)
+            .await?;
+
+        Ok(res.attributes)
+    }
+}
+
+#[derive(Debug)]
+pub struct UnassignAllocation<'a> {
+    client: &'a Client,
+    id: String,
+    allocation: i32,
+}
+
+impl<'a> UnassignAllocation<'a> {
+    #[doc(hidden)]
+    pub fn new(client: &'a Client, id: &str, allocation: i32) -> Self {
+        Self {
+            client,
+            id: id.to_string(),
+            allocation,
+        }
+    }
+
+    /// Asynchronously executes the request, removing the allocation from the server.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an [`Error`] if the request fails, if the allocation is not found or if it is the
+    /// primary allocation of the server.
+    pub async fn exec(self) -> Result<(), Error> {
+        let builder = Builder::new(
+            Route::UnassignAllocation {
+                id: self.id,
+                allocation: self.allocation,
+            }
+            .into(),
+        );
+
+        self.client.request::<()>(builder).await
+    }
+}
@@ -0,0 +1,551 @@
+use pteroxide_models::{
+    client::{Cron, Schedule, Task, TaskAction},
+    fractal::{ErrorData, FractalError, FractalItem, FractalList},
+};
+use serde::Serialize;
+
+use crate::{routing::Client as Route, Builder, Client, Error};
+
+#[derive(Debug)]
+pub struct GetSchedules<'a> {
+    client: &'a Client,
+    id: String,
+}
+
+impl<'a> GetSchedules<'a> {
+    #[doc(hidden)]
+    pub fn new(client: &'a Client, id: &str) -> Self {
+        Self {
+            client,
+            id: id.to_string(),
+        }
+    }
+
+    /// Asynchronously executes the request and returns a list of [`Schedule`] objects, including
+    /// their tasks.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an [`Error`] if the request fails or if the server is not found.
+    pub async fn exec(&self) -> Result<Vec<Schedule>, Error> {
+        let builder = Builder::new(
+            Route::GetSchedules {
+                id: self.id.clone(),
+            }
+            .into(),
+        );
+        let res = self
+            .client
+            .request::<FractalList<Schedule>>(builder)
+            .await?;
+
+        Ok(res.data.into_iter().map(|s| s.attributes).collect())
+    }
+}
+
+#[derive(Debug)]
+pub struct GetSchedule<'a> {
+    client: &'a Client,
+    id: String,
+    schedule: i32,
+}
+
+impl<'a> GetSchedule<'a> {
+    #[doc(hidden)]
+    pub fn new(client: &'a Client, id: &str, schedule: i32) -> Self {
+        Self {
+            client,
+            id: id.to_string(),
+            schedule,
+        }
+    }
+
+    /// Asynchronously executes the request and returns a [`Schedule`] object, including its
+    /// tasks.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an [`Error`] if the request fails or if the schedule is not found.
+    pub async fn exec(&self) -> Result<Schedule, Error> {
+        let builder = Builder::new(
+            Route::GetSchedule {
+                id: self.id.clone(),
+                schedule: self.schedule,
+            }
+            .into(),
+        );
+        let res = self
+            .client
+            .request::<FractalItem<Schedule>>(builder)
+            .await?;
+
+        Ok(res.attributes)
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+struct ScheduleFields<'a> {
+    pub name: &'a str,
+    pub is_active: bool,
+    pub only_when_online: bool,
+    #[serde(flatten)]
+    pub cron: Option<Cron>,
+}
+
+#[derive(Debug)]
+pub struct CreateSchedule<'a> {
+    client: &'a Client,
+    id: String,
+    fields: ScheduleFields<'a>,
+}
+
+impl<'a> CreateSchedule<'a> {
+    #[doc(hidden)]
+    pub fn new(client: &'a Client, id: &str) -> Self {
+        Self {
+            client,
+            id: id.to_string(),
+            fields: ScheduleFields {
+                is_active: true,
+                ..Default::default()
+            },
+        }
+    }
+
+    /// Sets the name of the schedule. This field is required.
+    pub fn name(mut self, name: &'a str) -> Self {
+        self.fields.name = name;
+
+        self
+    }
+
+    /// Sets the [`Cron`] expression for when the schedule runs. This field is required.
+    pub fn cron(mut self, cron: Cron) -> Self {
+        self.fields.cron = Some(cron);
+
+        self
+    }
+
+    /// Whether the schedule should run automatically. Defaults to `true`.
+    pub fn active(mut self, value: bool) -> Self {
+        self.fields.is_active = value;
+
+        self
+    }
+
+    /// Whether the schedule should only run when the server is online. Defaults to `false`.
+    pub fn only_when_online(mut self, value: bool) -> Self {
+        self.fields.only_when_online = value;
+
+        self
+    }
+
+    /// Asynchronously executes the request and returns the new [`Schedule`] object.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an [`Error`] if the request fails or a field does not satisfy a validation rule.
+    pub async fn exec(self) -> Result<Schedule, Error> {
+        let builder = Builder::new(Route::CreateSchedule { id: self.id }.into()).json(self.fields);
+        let res = self
+            .client
+            .request::<FractalItem<Schedule>>(builder)
+            .await?;
+
+        Ok(res.attributes)
+    }
+}
+
+#[derive(Debug)]
+pub struct UpdateSchedule<'a> {
+    client: &'a Client,
+    id: String,
+    schedule: i32,
+    name: Option<&'a str>,
+    cron: Option<Cron>,
+    is_active: Option<bool>,
+    only_when_online: Option<bool>,
+}
+
+impl<'a> UpdateSchedule<'a> {
+    #[doc(hidden)]
+    pub fn new(client: &'a Client, id: &str, schedule: i32) -> Self {
+        Self {
+            client,
+            id: id.to_string(),
+            schedule,
+            name: None,
+            cron: None,
+            is_active: None,
+            only_when_online: None,
+        }
+    }
+
+    /// Sets the name of the schedule, otherwise defaults to the existing one.
+    pub fn name(mut self, name: &'a str) -> Self {
+        self.name = Some(name);
+
+        self
+    }
+
+    /// Sets the [`Cron`] expression for when the schedule runs, otherwise defaults to the
+    /// existing one.
+    pub fn cron(mut self, cron: Cron) -> Self {
+        self.cron = Some(cron);
+
+        self
+    }
+
+    /// Sets whether the schedule should run automatically, otherwise defaults to the current
+    /// status.
+    pub fn active(mut self, value: bool) -> Self {
+        self.is_active = Some(value);
+
+        self
+    }
+
+    /// Sets whether the schedule should only run when the server is online, otherwise defaults
+    /// to the current status.
+    pub fn only_when_online(mut self, value: bool) -> Self {
+        self.only_when_online = Some(value);
+
+        self
+    }
+
+    /// Asynchronously executes the request and returns the updated [`Schedule`] object.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an [`Error`] if the request fails, if the schedule is not found or a field does
+    /// not satisfy a validation rule.
+    pub async fn exec(self) -> Result<Schedule, Error> {
+        let schedule = GetSchedule::new(self.client, &self.id, self.schedule)
+            .exec()
+            .await?;
+
+        let fields = ScheduleFields {
+            name: self.name.unwrap_or(&schedule.name),
+            is_active: self.is_active.unwrap_or(schedule.is_active),
+            only_when_online: self.only_when_online.unwrap_or(schedule.only_when_online),
+            cron: Some(self.cron.unwrap_or(schedule.cron)),
+        };
+        let builder = Builder::new(
+            Route::UpdateSchedule {
+                id: self.id,
+                schedule: self.schedule,
+            }
+            .into(),
+        )
+        .json(fields);
+        let res = self
+            .client
+            .request::<FractalItem<Schedule>>(builder)
+            .await?;
+
+        Ok(res.attributes)
+    }
+}
+
+#[derive(Debug)]
+pub struct ExecuteSchedule<'a> {
+    client: &'a Client,
+    id: String,
+    schedule: i32,
+}
+
+impl<'a> ExecuteSchedule<'a> {
+    #[doc(hidden)]
+    pub fn new(client: &'a Client, id: &str, schedule: i32) -> Self {
+        Self {
+            client,
+            id: id.to_string(),
+            schedule,
+        }
+    }
+
+    /// Asynchronously executes the request, running the schedule immediately. Note that the
+    /// tasks are run in the background, so they are not complete when this returns.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an [`Error`] if the request fails, if the schedule is not found or if it has no
+    /// tasks.
+    pub async fn exec(self) -> Result<(), Error> {
+        let builder = Builder::new(
+            Route::ExecuteSchedule {
+                id: self.id,
+                schedule: self.schedule,
+            }
+            .into(),
+        );
+
+        // the panel responds with an empty JSON array rather than no content
+        self.client.http.request_raw(builder).await.map(|_| ())
+    }
+}
+
+#[derive(Debug)]
+pub struct DeleteSchedule<'a> {
+    client: &'a Client,
+    id: String,
+    schedule: i32,
+}
+
+impl<'a> DeleteSchedule<'a> {
+    #[doc(hidden)]
+    pub fn new(client: &'a Client, id: &str, schedule: i32) -> Self {
+        Self {
+            client,
+            id: id.to_string(),
+            schedule,
+        }
+    }
+
+    /// Asynchronously executes the request. This also deletes all tasks of the schedule.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an [`Error`] if the request fails or if the schedule is not found.
+    pub async fn exec(self) -> Result<(), Error> {
+        let builder = Builder::new(
+            Route::DeleteSchedule {
+                id: self.id,
+                schedule: self.schedule,
+            }
+            .into(),
+        );
+
+        self.client.request::<()>(builder).await
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct TaskFields {
+    #[serde(flatten)]
+    pub action: Option<TaskAction>,
+    pub time_offset: i32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sequence_id: Option<i32>,
+    pub continue_on_failure: bool,
+}
+
+#[derive(Debug)]
+pub struct CreateTask<'a> {
+    client: &'a Client,
+    id: String,
+    schedule: i32,
+    fields: TaskFields,
+}
+
+impl<'a> CreateTask<'a> {
+    #[doc(hidden)]
+    pub fn new(client: &'a Client, id: &str, schedule: i32) -> Self {
+        Self {
+            client,
+            id: id.to_string(),
+            schedule,
+            fields: TaskFields {
+                action: None,
+                time_offset: 0,
+                sequence_id: None,
+                continue_on_failure: false,
+            },
+        }
+    }
+
+    /// Sets the [`TaskAction`] the task performs. This field is required.
+    pub fn action(mut self, action: TaskAction) -> Self {
+        self.fields.action = Some(action);
+
+        self
+    }
+
+    /// Sets the time to wait after the previous task before running this task, in seconds (up
+    /// to 900). Defaults to `0`.
+    pub fn time_offset(mut self, offset: i32) -> Self {
+        self.fields.time_offset = offset;
+
+        self
+    }
+
+    /// Sets the position of the task in the schedule. By default, the task is added after the
+    /// existing tasks.
+    pub fn sequence_id(mut self, id: i32) -> Self {
+        self.fields.sequence_id = Some(id);
+
+        self
+    }
+
+    /// Whether the following tasks should still run if this task fails. Defaults to `false`.
+    pub fn continue_on_failure(mut self, value: bool) -> Self {
+        self.fields.continue_on_failure = value;
+
+        self
+    }
+
+    /// Asynchronously executes the request and returns the new [`Task`] object.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an [`Error`] if the request fails, if the schedule has reached its task limit or a
+    /// field does not satisfy a validation rule.
+    pub async fn exec(self) -> Result<Task, Error> {
+        let builder = Builder::new(
+            Route::CreateTask {
+                id: self.id,
+                schedule: self.schedule,
+            }
+            .into(),
+        )
+        .json(self.fields);
+        let res = self.client.request::<FractalItem<Task>>(builder).await?;
+
+        Ok(res.attributes)
+    }
+}
+
+#[derive(Debug)]
+pub struct UpdateTask<'a> {
+    client: &'a Client,
+    id: String,
+    schedule: i32,
+    task: i32,
+    action: Option<TaskAction>,
+    time_offset: Option<i32>,
+    sequence_id: Option<i32>,
+    continue_on_failure: Option<bool>,
+}
+
+impl<'a> UpdateTask<'a> {
+    #[doc(hidden)]
+    pub fn new(client: &'a Client, id: &str, schedule: i32, task: i32) -> Self {
+        Self {
+            client,
+            id: id.to_string(),
+            schedule,
+            task,
+            action: None,
+            time_offset: None,
+            sequence_id: None,
+            continue_on_failure: None,
+        }
+    }
+
+    /// Sets the [`TaskAction`] the task performs, otherwise defaults to the existing one.
+    pub fn action(mut self, action: TaskAction) -> Self {
+        self.action = Some(action);
+
+        self
+    }
+
+    /// Sets the time to wait after the previous task before running this task, in seconds (up
+    /// to 900), otherwise defaults to the existing one.
+    pub fn time_offset(mut self, offset: i32) -> Self {
+        self.time_offset = Some(offset);
+
+        self
+    }
+
+    /// Sets the position of the task in the schedule, moving the other tasks accordingly.
+    pub fn sequence_id(mut self, id: i32) -> Self {
+        self.sequence_id = Some(id);
+
+        self
+    }
+
+    /// Sets whether the following tasks should still run if this task fails, otherwise defaults
+    /// to the current status.
+    pub fn continue_on_failure(mut self, value: bool) -> Self {
+        self.continue_on_failure = Some(value);
+
+        self
+    }
+
+    /// Asynchronously executes the request and returns the updated [`Task`] object.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an [`Error`] if the request fails, if the schedule or task is not found or a field
+    /// does not satisfy a validation rule. A task that is not in the schedule is reported as a
+    /// [`FractalError`][crate::error::ErrorKind::FractalError] with the `NotFoundHttpException`
+    /// code, the same as a schedule that does not exist.
+    pub async fn exec(self) -> Result<Task, Error> {
+        // the panel has no endpoint for a single task, so the existing one is taken from the
+        // schedule relationships
+        let task = GetSchedule::new(self.client, &self.id, self.schedule)
+            .exec()
+            .await?
+            .relationships
+            .and_then(|r| r.tasks)
+            .and_then(|t| t.into_iter().find(|t| t.id == self.task))
+            .ok_or_else(|| {
+                // mirror the error the panel returns for a task in another schedule
+                Error::from(FractalError {
+                    errors: vec![ErrorData {
+                        code: "NotFoundHttpException".to_string(),
+                        status: "404".to_string(),
+                        detail: "The requested resource could not be found on the server."
+                            .to_string(),
+                    }],
+                })
+            })?;
+
+        let fields = TaskFields {
+            action: Some(self.action.unwrap_or(task.action)),
+            time_offset: self.time_offset.unwrap_or(task.time_offset),
+            sequence_id: self.sequence_id,
+            continue_on_failure: self.continue_on_failure.unwrap_or(task.continue_on_failure),
+        };
+        let builder = Builder::new(
+            Route::UpdateTask {
+                id: self.id,
+                schedule: self.schedule,
+                task: self.task,
+            }
+            .into(),
+        )
+        .json(fields);
+        let res = self.client.request::<FractalItem<Task>>(builder).await?;
+
+        Ok(res.attributes)
+    }
+}
+
+#[derive(Debug)]
+pub struct DeleteTask<'a> {
+    client: &'a Client,
+    id: String,
+    schedule: i32,
+    task: i32,
+}
+
+impl<'a> DeleteTask<'a> {
+    #[doc(hidden)]
+    pub fn new(client: &'a Client, id: &str, schedule: i32, task: i32) -> Self {
+        Self {
+            client,
+            id: id.to_string(),
+            schedule,
+            task,
+        }
+    }
+
+    /// Asynchronously executes the request. The remaining tasks of the schedule are moved to fill
+    /// the gap.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an [`Error`] if the request fails or if the schedule or task is not found.
+    pub async fn exec(self) -> Result<(), Error> {
+        let builder = Builder::new(
+            Route::DeleteTask {
+                id: self.id,
+                schedule: self.schedule,
+                task: self.task,
+            }
+            .into(),
+        );
+
+        self.client.request::<()>(builder).await
+    }
+}
@@ -0,0 +1,264 @@
+use pteroxide_models::{
+    client::{Resources, Server, WebSocketAuth},
+    fractal::FractalItem,
+    PowerSignal,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    response::{impl_paginate, Response},
+    routing::Client as Route,
+    Builder, Client, Error,
+};
+
+/// The type of servers to list, based on the user's access to them.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ServerType {
+    /// Servers the user has access to as an admin but does not own or is not a subuser of.
+    Admin,
+    /// All servers on the panel, if the user is an admin.
+    AdminAll,
+    /// Servers the user owns.
+    Owner,
+}
+
+impl ServerType {
+    /// Returns the value of the server type used in the request query.
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            ServerType::Admin => "admin",
+            ServerType::AdminAll => "admin-all",
+            ServerType::Owner => "owner",
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct GetServers<'a> {
+    client: &'a Client,
+    server_type: Option<ServerType>,
+    page: u32,
+    per_page: u32,
+}
+
+impl<'a> GetServers<'a> {
+    #[doc(hidden)]
+    pub const fn new(client: &'a Client) -> Self {
+        Self {
+            client,
+            server_type: None,
+            page: 1,
+            per_page: 50,
+        }
+    }
+
+    /// Sets the [`ServerType`] to filter the servers by. By default, only the servers the user
+    /// owns or is a subuser of are returned.
+    pub fn server_type(mut self, value: ServerType) -> Self {
+        self.server_type = Some(value);
+
+        self
+    }
+
+    /// Returns a paginated [`Response`] for the request, which can fetch individual pages, collect
+    /// all pages or lazily walk through them as a stream.
+    pub fn paginate(&self) -> Response<'a, Server> {
+        let mut res = Response::new(&self.client.http, Route::GetServers.into())
+            .page(self.page)
+            .per_page(self.per_page);
+
+        if let Some(t) = self.server_type {
+            res = res.param("type", t.as_str());
+        }
+
+        res
+    }
+}
+
+impl_paginate!(GetServers, Server, "servers");
+
+#[derive(Debug)]
+pub struct GetServer<'a> {
+    client: &'a Client,
+    id: String,
+}
+
+impl<'a> GetServer<'a> {
+    #[doc(hidden)]
+    pub fn new(client: &'a Client, id: &str) -> Self {
+        Self {
+            client,
+            id: id.to_string(),
+        }
+    }
+
+    /// Asynchronously executes the request and returns a [`Server`] object.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an [`Error`] if the request fails or if the server is not found.
+    pub async fn exec(&self) -> Result<Server, Error> {
+        let builder = Builder::new(
+            Route::GetServer {
+                id: self.id.clone(),
+            }
+            .into(),
+        );
+        let res = self.client.request::<FractalItem<Server>>(builder).await?;
+
+        Ok(res.attributes)
+    }
+}
+
+#[derive(Debug)]
+pub struct GetServerResources<'a> {
+    client: &'a Client,
+    id: String,
+}
+
+impl<'a> GetServerResources<'a> {
+    #[doc(hidden)]
+    pub fn new(client: &'a Client, id: &str) -> Self {
+        Self {
+            client,
+            id: id.to_string(),
+        }
+    }
+
+    /// Asynchronously executes the request and returns the [`Resources`] object with the current
+    /// state and resource usage of the server.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an [`Error`] if the request fails, if the server is not found or if the node the
+    /// server is on cannot be reached.
+    pub async fn exec(&self) -> Result<Resources, Error> {
+        let builder = Builder::new(
+            Route::GetServerResources {
+                id: self.id.clone(),
+            }
+            .into(),
+        );
+        let res = self
+            .client
+            .request::<FractalItem<Resources>>(builder)
+            .await?;
+
+        Ok(res.attributes)
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct SendPowerSignalFields {
+    pub signal: PowerSignal,
+}
+
+#[derive(Debug)]
+pub struct SendPowerSignal<'a> {
+    client: &'a Client,
+    id: String,
+    fields: SendPowerSignalFields,
+}
+
+impl<'a> SendPowerSignal<'a> {
+    #[doc(hidden)]
+    pub fn new(client: &'a Client, id: &str, signal: PowerSignal) -> Self {
+        Self {
+            client,
+            id: id.to_string(),
+            fields: SendPowerSignalFields { signal },
+        }
+    }
+
+    /// Asynchronously executes the request. Note that the panel returns once the signal has been
+    /// sent to the node, not once the server has changed its state.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an [`Error`] if the request fails or if the server is not found.
+    pub async fn exec(self) -> Result<(), Error> {
+        let builder = Builder::new(Route::SendPowerSignal { id: self.id }.into()).json(self.fields);
+
+        self.client.request::<()>(builder).await
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct SendCommandFields<'a> {
+    pub command: &'a str,
+}
+
+#[derive(Debug)]
+pub struct SendCommand<'a> {
+    client: &'a Client,
+    id: String,
+    fields: SendCommandFields<'a>,
+}
+
+impl<'a> SendCommand<'a> {
+    #[doc(hidden)]
+    pub fn new(client: &'a Client, id: &str, command: &'a str) -> Self {
+        Self {
+            client,
+            id: id.to_string(),
+            fields: SendCommandFields { command },
+        }
+    }
+
+    /// Asynchronously executes the request.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an [`Error`] if the request fails or if the server is not found. If the server is
+    /// offline, the error is a [`ServerOfflineError`].
+    ///
+    /// [`ServerOfflineError`]: crate::error::ErrorKind::ServerOfflineError
+    pub async fn exec(self) -> Result<(), Error> {
+        let builder = Builder::new(Route::SendCommand { id: self.id }.into()).json(self.fields);
+
+        self.client
+            .request::<()>(builder)
+            .await
+            .map_err(Error::server_offline)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct WebSocketResponse {
+    data: WebSocketAuth,
+}
+
+#[derive(Debug)]
+pub struct GetWebSocket<'a> {
+    client: &'a Client,
+    id: String,
+}
+
+impl<'a> GetWebSocket<'a> {
+    #[doc(hidden)]
+    pub fn new(client: &'a Client, id: &str) -> Self {
+        Self {
+            client,
+            id: id.to_string(),
+        }
+    }
+
+    /// Asynchronously executes the request and returns the [`WebSocketAuth`] object with the
+    /// token and socket URL for the server. Note that the token expires after 10 minutes.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an [`Error`] if the request fails, if the server is not found or if the user does
+    /// not have permission to connect to the console.
+    pub async fn exec(&self) -> Result<WebSocketAuth, Error> {
+        let builder = Builder::new(
+            Route::GetWebSocket {
+                id: self.id.clone(),
+            }
+            .into(),
+        );
+        let res = self.client.request::<WebSocketResponse>(builder).await?;
+
+        Ok(res.data)
+    }
+}
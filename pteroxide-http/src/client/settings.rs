@@ -0,0 +1,118 @@
+use serde::Serialize;
+
+use crate::{routing::Client as Route, Builder, Client, Error};
+
+#[derive(Debug, Serialize)]
+struct RenameServerFields<'a> {
+    pub name: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<&'a str>,
+}
+
+#[derive(Debug)]
+pub struct RenameServer<'a> {
+    client: &'a Client,
+    id: String,
+    fields: RenameServerFields<'a>,
+}
+
+impl<'a> RenameServer<'a> {
+    #[doc(hidden)]
+    pub fn new(client: &'a Client, id: &str, name: &'a str) -> Self {
+        Self {
+            client,
+            id: id.to_string(),
+            fields: RenameServerFields {
+                name,
+                description: None,
+            },
+        }
+    }
+
+    /// Sets the description of the server, otherwise the existing one is kept.
+    pub fn description(mut self, description: &'a str) -> Self {
+        self.fields.description = Some(description);
+
+        self
+    }
+
+    /// Asynchronously executes the request.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an [`Error`] if the request fails or a field does not satisfy a validation rule.
+    pub async fn exec(self) -> Result<(), Error> {
+        let builder = Builder::new(Route::RenameServer { id: self.id }.into()).json(self.fields);
+
+        self.client.request::<()>(builder).await
+    }
+}
+
+#[derive(Debug)]
+pub struct ReinstallServer<'a> {
+    client: &'a Client,
+    id: String,
+}
+
+impl<'a> ReinstallServer<'a> {
+    #[doc(hidden)]
+    pub fn new(client: &'a Client, id: &str) -> Self {
+        Self {
+            client,
+            id: id.to_string(),
+        }
+    }
+
+    /// Asynchronously executes the request. Note that the server is reinstalled in the
+    /// background, so it is not complete when this returns.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an [`Error`] if the request fails or if the server is not in a state that allows
+    /// reinstalling.
+    pub async fn exec(self) -> Result<(), Error> {
+        let builder = Builder::new(Route::ReinstallServer { id: self.id }.into());
+
+        // the panel responds with an empty JSON array rather than no content
+        self.client.http.request_raw(builder).await.map(|_| ())
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct UpdateDockerImageFields<'a> {
+    pub docker_image: &'a str,
+}
+
+#[derive(Debug)]
+pub struct UpdateDockerImage<'a> {
+    client: &'a Client,
+    id: String,
+    fields: UpdateDockerImageFields<'a>,
+}
+
+impl<'a> UpdateDockerImage<'a> {
+    #[doc(hidden)]
+    pub fn new(client: &'a Client, id: &str, image: &'a str) -> Self {
+        Self {
+            client,
+            id: id.to_string(),
+            fields: UpdateDockerImageFields {
+                docker_image: image,
+            },
+        }
+    }
+
+    /// Asynchronously executes the request. Note that the server must be restarted for the
+    /// change to take effect.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an [`Error`] if the request fails, if the image is not one of the images allowed
+    /// by the egg or if the current image was set by an administrator.
+    pub async fn exec(self) -> Result<(), Error> {
+        let builder =
+            Builder::new(Route::UpdateDockerImage { id: self.id }.into()).json(self.fields);
+
+        self.client.request::<()>(builder).await
+    }
+}
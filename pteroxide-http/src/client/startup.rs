@@ -0,0 +1,101 @@
+use pteroxide_models::{
+    client::{Startup, StartupVariable},
+    fractal::FractalItem,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::{routing::Client as Route, Builder, Client, Error};
+
+#[derive(Debug, Deserialize)]
+struct StartupMeta {
+    startup_command: String,
+    raw_startup_command: String,
+    docker_images: HashMap<String, String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct StartupResponse {
+    data: Vec<FractalItem<StartupVariable>>,
+    meta: StartupMeta,
+}
+
+#[derive(Debug)]
+pub struct GetStartup<'a> {
+    client: &'a Client,
+    id: String,
+}
+
+impl<'a> GetStartup<'a> {
+    #[doc(hidden)]
+    pub fn new(client: &'a Client, id: &str) -> Self {
+        Self {
+            client,
+            id: id.to_string(),
+        }
+    }
+
+    /// Asynchronously executes the request and returns the [`Startup`] object with the startup
+    /// command, docker images and variables of the server.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an [`Error`] if the request fails or if the server is not found.
+    pub async fn exec(&self) -> Result<Startup, Error> {
+        let builder = Builder::new(
+            Route::GetStartup {
+                id: self.id.clone(),
+            }
+            .into(),
+        );
+        let res = self.client.request::<StartupResponse>(builder).await?;
+
+        Ok(Startup {
+            startup_command: res.meta.startup_command,
+            raw_startup_command: res.meta.raw_startup_command,
+            docker_images: res.meta.docker_images,
+            variables: res.data.into_iter().map(|v| v.attributes).collect(),
+        })
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct UpdateStartupVariableFields<'a> {
+    pub key: &'a str,
+    pub value: &'a str,
+}
+
+#[derive(Debug)]
+pub struct UpdateStartupVariable<'a> {
+    client: &'a Client,
+    id: String,
+    fields: UpdateStartupVariableFields<'a>,
+}
+
+impl<'a> UpdateStartupVariable<'a> {
+    #[doc(hidden)]
+    pub fn new(client: &'a Client, id: &str, key: &'a str, value: &'a str) -> Self {
+        Self {
+            client,
+            id: id.to_string(),
+            fields: UpdateStartupVariableFields { key, value },
+        }
+    }
+
+    /// Asynchronously executes the request and returns the updated [`StartupVariable`] object.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an [`Error`] if the request fails, if the variable is not found or is not
+    /// editable, or if the value does not satisfy the variable rules.
+    pub async fn exec(self) -> Result<StartupVariable, Error> {
+        let builder =
+            Builder::new(Route::UpdateStartupVariable { id: self.id }.into()).json(self.fields);
+        let res = self
+            .client
+            .request::<FractalItem<StartupVariable>>(builder)
+            .await?;
+
+        Ok(res.attributes)
+    }
+}
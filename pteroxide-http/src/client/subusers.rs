@@ -0,0 +1,260 @@
+use pteroxide_models::{
+    client::{PermissionGroup, SubUser},
+    fractal::{FractalItem, FractalList},
+    Permission,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::{routing::Client as Route, Builder, Client, Error};
+
+#[derive(Debug, Deserialize)]
+struct PermissionsResponse {
+    permissions: HashMap<String, PermissionGroup>,
+}
+
+#[derive(Debug)]
+pub struct GetPermissions<'a> {
+    client: &'a Client,
+}
+
+impl<'a> GetPermissions<'a> {
+    #[doc(hidden)]
+    pub const fn new(client: &'a Client) -> Self {
+        Self { client }
+    }
+
+    /// Asynchronously executes the request and returns a map of the permission groups on the
+    /// panel to their [`PermissionGroup`] objects. This includes any permissions added by addons
+    /// that are not known to [`Permission`].
+    ///
+    /// ## Errors
+    ///
+    /// Returns an [`Error`] if the request fails.
+    pub async fn exec(&self) -> Result<HashMap<String, PermissionGroup>, Error> {
+        let builder = Builder::new(Route::GetPermissions.into());
+        let res = self
+            .client
+            .request::<FractalItem<PermissionsResponse>>(builder)
+            .await?;
+
+        Ok(res.attributes.permissions)
+    }
+}
+
+#[derive(Debug)]
+pub struct GetSubUsers<'a> {
+    client: &'a Client,
+    id: String,
+}
+
+impl<'a> GetSubUsers<'a> {
+    #[doc(hidden)]
+    pub fn new(client: &'a Client, id: &str) -> Self {
+        Self {
+            client,
+            id: id.to_string(),
+        }
+    }
+
+    /// Asynchronously executes the request and returns a list of [`SubUser`] objects.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an [`Error`] if the request fails or if the server is not found.
+    pub async fn exec(&self) -> Result<Vec<SubUser>, Error> {
+        let builder = Builder::new(
+            Route::GetSubUsers {
+                id: self.id.clone(),
+            }
+            .into(),
+        );
+        let res = self.client.request::<FractalList<SubUser>>(builder).await?;
+
+        Ok(res.data.into_iter().map(|u| u.attributes).collect())
+    }
+}
+
+#[derive(Debug)]
+pub struct GetSubUser<'a> {
+    client: &'a Client,
+    id: String,
+    user: String,
+}
+
+impl<'a> GetSubUser<'a> {
+    #[doc(hidden)]
+    pub fn new(client: &'a Client, id: &str, user: &str) -> Self {
+        Self {
+            client,
+            id: id.to_string(),
+            user: user.to_string(),
+        }
+    }
+
+    /// Asynchronously executes the request and returns a [`SubUser`] object.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an [`Error`] if the request fails or if the subuser is not found.
+    pub async fn exec(&self) -> Result<SubUser, Error> {
+        let builder = Builder::new(
+            Route::GetSubUser {
+                id: self.id.clone(),
+                user: self.user.clone(),
+            }
+            .into(),
+        );
+        let res = self.client.request::<FractalItem<SubUser>>(builder).await?;
+
+        Ok(res.attributes)
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+struct SubUserFields<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<&'a str>,
+    pub permissions: Vec<Permission>,
+}
+
+#[derive(Debug)]
+pub struct CreateSubUser<'a> {
+    client: &'a Client,
+    id: String,
+    fields: SubUserFields<'a>,
+}
+
+impl<'a> CreateSubUser<'a> {
+    #[doc(hidden)]
+    pub fn new(client: &'a Client, id: &str, email: &'a str) -> Self {
+        Self {
+            client,
+            id: id.to_string(),
+            fields: SubUserFields {
+                email: Some(email),
+                permissions: Vec::new(),
+            },
+        }
+    }
+
+    /// Adds a [`Permission`] to give the subuser.
+    pub fn permission(mut self, permission: Permission) -> Self {
+        self.fields.permissions.push(permission);
+
+        self
+    }
+
+    /// Sets the [`Permission`]s to give the subuser, replacing any previously added ones.
+    pub fn permissions(mut self, permissions: Vec<Permission>) -> Self {
+        self.fields.permissions = permissions;
+
+        self
+    }
+
+    /// Asynchronously executes the request and returns the new [`SubUser`] object. If the email
+    /// does not belong to an existing user, the panel creates an account and sends an invite to
+    /// the email.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an [`Error`] if the request fails, if the user is already a subuser of the server
+    /// or if a permission is not one the requesting user has.
+    pub async fn exec(self) -> Result<SubUser, Error> {
+        let builder = Builder::new(Route::CreateSubUser { id: self.id }.into()).json(self.fields);
+        let res = self.client.request::<FractalItem<SubUser>>(builder).await?;
+
+        Ok(res.attributes)
+    }
+}
+
+#[derive(Debug)]
+pub struct UpdateSubUser<'a> {
+    client: &'a Client,
+    id: String,
+    user: String,
+    fields: SubUserFields<'a>,
+}
+
+impl<'a> UpdateSubUser<'a> {
+    #[doc(hidden)]
+    pub fn new(client: &'a Client, id: &str, user: &str) -> Self {
+        Self {
+            client,
+            id: id.to_string(),
+            user: user.to_string(),
+            fields: Default::default(),
+        }
+    }
+
+    /// Adds a [`Permission`] to give the subuser.
+    pub fn permission(mut self, permission: Permission) -> Self {
+        self.fields.permissions.push(permission);
+
+        self
+    }
+
+    /// Sets the [`Permission`]s to give the subuser, replacing any previously added ones.
+    pub fn permissions(mut self, permissions: Vec<Permission>) -> Self {
+        self.fields.permissions = permissions;
+
+        self
+    }
+
+    /// Asynchronously executes the request and returns the updated [`SubUser`] object. Note that
+    /// this replaces all existing permissions of the subuser with the ones set.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an [`Error`] if the request fails, if the subuser is not found or if a permission
+    /// is not one the requesting user has.
+    pub async fn exec(self) -> Result<SubUser, Error> {
+        let builder = Builder::new(
+            Route::UpdateSubUser {
+                id: self.id,
+                user: self.user,
+            }
+            .into(),
+        )
+        .json(self.fields);
+        let res = self.client.request::<FractalItem<SubUser>>(builder).await?;
+
+        Ok(res.attributes)
+    }
+}
+
+#[derive(Debug)]
+pub struct DeleteSubUser<'a> {
+    client: &'a Client,
+    id: String,
+    user: String,
+}
+
+impl<'a> DeleteSubUser<'a> {
+    #[doc(hidden)]
+    pub fn new(client: &'a Client, id: &str, user: &str) -> Self {
+        Self {
+            client,
+            id: id.to_string(),
+            user: user.to_string(),
+        }
+    }
+
+    /// Asynchronously executes the request. This removes the user's access to the server but
+    /// does not delete their account.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an [`Error`] if the request fails or if the subuser is not found.
+    pub async fn exec(self) -> Result<(), Error> {
+        let builder = Builder::new(
+            Route::DeleteSubUser {
+                id: self.id,
+                user: self.user,
+            }
+            .into(),
+        );
+
+        self.client.request::<()>(builder).await
+    }
+}
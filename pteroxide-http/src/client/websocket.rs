@@ -0,0 +1,329 @@
+use futures_util::{
+    stream::{self, SplitSink, SplitStream, Stream},
+    SinkExt, StreamExt,
+};
+use hyper::StatusCode;
+use pteroxide_models::{
+    client::{BackupCompleted, PowerState, WebSocketStats},
+    PowerSignal,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::sync::Arc;
+use tokio::{net::TcpStream, sync::Mutex};
+use tokio_tungstenite::{
+    tungstenite::{client::IntoClientRequest, http::HeaderValue, Message},
+    MaybeTlsStream, WebSocketStream,
+};
+
+use super::servers::GetWebSocket;
+use crate::{Client, Error};
+
+type Socket = WebSocketStream<MaybeTlsStream<TcpStream>>;
+
+/// Represents an event received from the websocket of a server.
+#[derive(Clone, Debug, PartialEq)]
+pub enum WebSocketEvent {
+    /// The websocket was authenticated with the token.
+    AuthSuccess,
+    /// A line of output from the server console.
+    ConsoleOutput(String),
+    /// The power state of the server changed.
+    Status(PowerState),
+    /// The current resource usage of the server.
+    Stats(WebSocketStats),
+    /// A line of output from the server installation process.
+    InstallOutput(String),
+    InstallStarted,
+    InstallCompleted,
+    /// A backup of the server was completed.
+    BackupCompleted(BackupCompleted),
+    BackupRestoreCompleted,
+    /// The token will expire soon. The stream requests a new token and re-authenticates before
+    /// yielding this event.
+    TokenExpiring,
+    /// The token expired. The stream requests a new token and re-authenticates before yielding
+    /// this event.
+    TokenExpired,
+    /// A message from the node, such as a power action being performed.
+    DaemonMessage(String),
+    /// An error from the node.
+    DaemonError(String),
+    /// An error from the node caused by an invalid or missing token.
+    JwtError(String),
+    /// An event that is not handled by the library.
+    Unknown {
+        event: String,
+        args: Vec<String>,
+    },
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+struct Payload {
+    event: String,
+    #[serde(default)]
+    args: Option<Vec<String>>,
+}
+
+impl Payload {
+    fn into_event(self) -> Result<WebSocketEvent, Error> {
+        let mut args = self.args.unwrap_or_default();
+        let arg = if args.is_empty() {
+            String::new()
+        } else {
+            args.swap_remove(0)
+        };
+
+        let event = match self.event.as_str() {
+            "auth success" => WebSocketEvent::AuthSuccess,
+            "console output" => WebSocketEvent::ConsoleOutput(arg),
+            "status" => WebSocketEvent::Status(parse_str(arg)?),
+            "stats" => WebSocketEvent::Stats(parse(&arg)?),
+            "install output" => WebSocketEvent::InstallOutput(arg),
+            "install started" => WebSocketEvent::InstallStarted,
+            "install completed" => WebSocketEvent::InstallCompleted,
+            "backup restore completed" => WebSocketEvent::BackupRestoreCompleted,
+            "token expiring" => WebSocketEvent::TokenExpiring,
+            "token expired" => WebSocketEvent::TokenExpired,
+            "daemon message" => WebSocketEvent::DaemonMessage(arg),
+            "daemon error" => WebSocketEvent::DaemonError(arg),
+            "jwt error" => WebSocketEvent::JwtError(arg),
+            // backup events are suffixed with the backup UUID
+            e if e.starts_with("backup completed") => WebSocketEvent::BackupCompleted(parse(&arg)?),
+            _ => {
+                if !arg.is_empty() {
+                    args.insert(0, arg);
+                }
+
+                WebSocketEvent::Unknown {
+                    event: self.event,
+                    args,
+                }
+            }
+        };
+
+        Ok(event)
+    }
+}
+
+fn parse<T>(data: &str) -> Result<T, Error>
+where
+    for<'de> T: Deserialize<'de>,
+{
+    let mut de = serde_json::Deserializer::from_str(data);
+
+    serde_path_to_error::deserialize(&mut de)
+        .map_err(|e| Error::deserialize(StatusCode::OK, data.as_bytes(), e))
+}
+
+// some events send unquoted strings, such as the power state
+fn parse_str<T>(data: String) -> Result<T, Error>
+where
+    for<'de> T: Deserialize<'de>,
+{
+    let value = Value::String(data);
+
+    serde_path_to_error::deserialize(&value)
+        .map_err(|e| Error::deserialize(StatusCode::OK, value.to_string().as_bytes(), e))
+}
+
+/// A handle for sending events to the websocket of a server. This can be cloned and used
+/// independently of the event stream.
+#[derive(Clone, Debug)]
+pub struct WebSocketSender {
+    sink: Arc<Mutex<SplitSink<Socket, Message>>>,
+}
+
+impl WebSocketSender {
+    async fn send(&self, event: &str, args: Vec<String>) -> Result<(), Error> {
+        let payload = Payload {
+            event: event.to_string(),
+            args: Some(args),
+        };
+        let data = serde_json::to_string(&payload).map_err(Error::websocket)?;
+
+        self.sink
+            .lock()
+            .await
+            .send(Message::Text(data))
+            .await
+            .map_err(Error::websocket)
+    }
+
+    /// Sends a command to the server console.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an [`Error`] if the message fails to be sent.
+    pub async fn send_command(&self, command: &str) -> Result<(), Error> {
+        self.send("send command", vec![command.to_string()]).await
+    }
+
+    /// Sends a [`PowerSignal`] to the server.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an [`Error`] if the message fails to be sent.
+    pub async fn set_state(&self, signal: PowerSignal) -> Result<(), Error> {
+        self.send("set state", vec![signal.as_str().to_string()])
+            .await
+    }
+
+    /// Requests the recent console output of the server, which is sent back as
+    /// [`ConsoleOutput`][WebSocketEvent::ConsoleOutput] events.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an [`Error`] if the message fails to be sent.
+    pub async fn send_logs(&self) -> Result<(), Error> {
+        self.send("send logs", vec![]).await
+    }
+
+    /// Requests the current resource usage of the server, which is sent back as a
+    /// [`Stats`][WebSocketEvent::Stats] event.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an [`Error`] if the message fails to be sent.
+    pub async fn send_stats(&self) -> Result<(), Error> {
+        self.send("send stats", vec![]).await
+    }
+
+    /// Closes the websocket connection. The event stream ends once the node acknowledges it.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an [`Error`] if the close message fails to be sent.
+    pub async fn close(&self) -> Result<(), Error> {
+        self.sink
+            .lock()
+            .await
+            .close()
+            .await
+            .map_err(Error::websocket)
+    }
+
+    async fn auth(&self, token: String) -> Result<(), Error> {
+        self.send("auth", vec![token]).await
+    }
+}
+
+struct EventState<'a> {
+    client: &'a Client,
+    id: String,
+    sender: WebSocketSender,
+    stream: SplitStream<Socket>,
+    done: bool,
+}
+
+impl EventState<'_> {
+    async fn refresh(&self) -> Result<(), Error> {
+        let auth = GetWebSocket::new(self.client, &self.id).exec().await?;
+
+        self.sender.auth(auth.token).await
+    }
+}
+
+#[derive(Debug)]
+pub struct ConnectWebSocket<'a> {
+    client: &'a Client,
+    id: String,
+}
+
+impl<'a> ConnectWebSocket<'a> {
+    #[doc(hidden)]
+    pub fn new(client: &'a Client, id: &str) -> Self {
+        Self {
+            client,
+            id: id.to_string(),
+        }
+    }
+
+    /// Asynchronously connects to the websocket of the server and authenticates with a new
+    /// token. Returns a [`WebSocketSender`] for sending events and a [`Stream`] of the
+    /// [`WebSocketEvent`]s received from the server.
+    ///
+    /// The token is refreshed automatically when the node sends a
+    /// [`TokenExpiring`][WebSocketEvent::TokenExpiring] or
+    /// [`TokenExpired`][WebSocketEvent::TokenExpired] event, so the stream must be polled for
+    /// the connection to stay authenticated. Events that fail to parse are yielded as errors
+    /// without ending the stream. The stream ends when the connection is closed, when the
+    /// connection fails, or when the token fails to be refreshed.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an [`Error`] if the token request fails or if the connection to the node fails.
+    pub async fn exec(
+        self,
+    ) -> Result<
+        (
+            WebSocketSender,
+            impl Stream<Item = Result<WebSocketEvent, Error>> + 'a,
+        ),
+        Error,
+    > {
+        let auth = GetWebSocket::new(self.client, &self.id).exec().await?;
+
+        // the node only accepts connections from the panel origin
+        let mut req = auth
+            .socket
+            .as_str()
+            .into_client_request()
+            .map_err(Error::websocket)?;
+        let origin = self.client.http.url.trim_end_matches('/');
+        req.headers_mut().insert(
+            "Origin",
+            HeaderValue::from_str(origin).map_err(Error::websocket)?,
+        );
+
+        let (socket, _) = tokio_tungstenite::connect_async(req)
+            .await
+            .map_err(Error::websocket)?;
+        let (sink, stream) = socket.split();
+        let sender = WebSocketSender {
+            sink: Arc::new(Mutex::new(sink)),
+        };
+        sender.auth(auth.token).await?;
+
+        let state = EventState {
+            client: self.client,
+            id: self.id,
+            sender: sender.clone(),
+            stream,
+            done: false,
+        };
+
+        let events = stream::unfold(state, |mut state| async move {
+            if state.done {
+                return None;
+            }
+
+            loop {
+                let data = match state.stream.next().await? {
+                    Ok(Message::Text(data)) => data,
+                    // keep reading after a close frame so the reply is sent and the stream ends
+                    Ok(_) => continue,
+                    Err(e) => {
+                        state.done = true;
+                        return Some((Err(Error::websocket(e)), state));
+                    }
+                };
+
+                let res = match parse::<Payload>(&data).and_then(Payload::into_event) {
+                    Ok(e @ (WebSocketEvent::TokenExpiring | WebSocketEvent::TokenExpired)) => {
+                        let res = state.refresh().await;
+                        // the connection is unusable once the token cannot be refreshed
+                        state.done = res.is_err();
+
+                        res.map(|_| e)
+                    }
+                    res => res,
+                };
+
+                return Some((res, state));
+            }
+        });
+
+        Ok((sender, events))
+    }
+}
@@ -1,9 +1,12 @@
+use hyper::StatusCode;
 use pteroxide_models::fractal::FractalError;
 use std::{
     error,
     fmt::{Display, Formatter, Result as FmtResult},
 };
 
+use crate::ratelimit::Ratelimit;
+
 /// Represents an interface for pteroxide-http errors, including errors received from the API.
 #[derive(Debug)]
 pub struct Error {
@@ -23,20 +26,60 @@ impl Error {
     pub fn into_source(self) -> Option<Box<dyn error::Error + Send + Sync>> {
         self.source
     }
+
+    /// Returns the [`ResponseBody`] details if the error was caused by an invalid response body.
+    pub const fn response_body(&self) -> Option<&ResponseBody> {
+        match &self.kind {
+            ErrorKind::DeserializeError(b) | ErrorKind::ResponseError(b) => Some(b),
+            _ => None,
+        }
+    }
+
+    /// Returns the [`Ratelimit`] information if the error was caused by a ratelimit.
+    pub const fn ratelimit(&self) -> Option<&Ratelimit> {
+        match &self.kind {
+            ErrorKind::RatelimitError(r) => Some(r),
+            _ => None,
+        }
+    }
 }
 
 impl Display for Error {
     fn fmt(&self, f: &mut Formatter) -> FmtResult {
         match &self.kind {
-            ErrorKind::DeserializeError => f.write_str("Failed to deserialize body into model"),
-            ErrorKind::RatelimitError => {
-                f.write_str("Received a ratelimit while processing request")
-            }
+            ErrorKind::DeserializeError(b) => match &b.path {
+                Some(p) => f.write_str(&format!(
+                    "Failed to deserialize body into model at '{}' ({})",
+                    p, b.status
+                )),
+                None => f.write_str(&format!(
+                    "Failed to deserialize body into model ({})",
+                    b.status
+                )),
+            },
+            ErrorKind::RatelimitError(r) => match r.retry_after {
+                Some(d) => f.write_str(&format!(
+                    "Received a ratelimit while processing request (retry after {}s)",
+                    d.as_secs()
+                )),
+                None => f.write_str("Received a ratelimit while processing request"),
+            },
             ErrorKind::RequestError => f.write_str("Request failed while processing"),
-            ErrorKind::FractalError(e) => f.write_str(&format!(
-                "Received an error from the API ({})",
-                e.errors[0].code
+            ErrorKind::TimeoutError => f.write_str("Request timed out while processing"),
+            ErrorKind::ResponseError(b) => f.write_str(&format!(
+                "Received an unexpected error response from the API ({})",
+                b.status
             )),
+            ErrorKind::WebSocketError => {
+                f.write_str("WebSocket connection failed while processing")
+            }
+            ErrorKind::ServerOfflineError => {
+                f.write_str("The server must be online to process the request")
+            }
+            ErrorKind::FractalError(e) => match e.errors.first() {
+                Some(d) => f.write_str(&format!("Received an error from the API ({})", d.code)),
+                None => f.write_str("Received an error from the API"),
+            },
         }
     }
 }
@@ -58,6 +101,83 @@ impl From<FractalError> for Error {
     }
 }
 
+impl Error {
+    /// Creates a new [`RequestError`][ErrorKind::RequestError] from the given source. This is
+    /// intended for custom [`Transport`][crate::Transport] implementations to report failures.
+    pub fn request<E>(source: E) -> Self
+    where
+        E: Into<Box<dyn error::Error + Send + Sync>>,
+    {
+        Self {
+            kind: ErrorKind::RequestError,
+            source: Some(source.into()),
+        }
+    }
+
+    #[cfg(feature = "websocket")]
+    pub(crate) fn websocket<E>(source: E) -> Self
+    where
+        E: Into<Box<dyn error::Error + Send + Sync>>,
+    {
+        Self {
+            kind: ErrorKind::WebSocketError,
+            source: Some(source.into()),
+        }
+    }
+
+    /// Converts the Fractal error the panel returns when a request requires the server to be
+    /// running into a [`ServerOfflineError`][ErrorKind::ServerOfflineError], keeping the
+    /// [`FractalError`] as the source. The panel reports this as an `HttpException` with a
+    /// `502 Bad Gateway` status, whereas a `DaemonConnectionException` means that the node
+    /// itself could not be reached and is returned unchanged, as are other errors.
+    #[cfg(feature = "client")]
+    pub(crate) fn server_offline(self) -> Self {
+        match &self.kind {
+            ErrorKind::FractalError(e)
+                if e.errors
+                    .iter()
+                    .any(|d| d.code == "HttpException" && d.status == "502") =>
+            {
+                Self {
+                    kind: ErrorKind::ServerOfflineError,
+                    source: self.source,
+                }
+            }
+            _ => self,
+        }
+    }
+
+    pub(crate) fn deserialize(
+        status: StatusCode,
+        body: &[u8],
+        e: serde_path_to_error::Error<serde_json::Error>,
+    ) -> Self {
+        let path = e.path().to_string();
+        let path = if path == "." { None } else { Some(path) };
+
+        Self {
+            kind: ErrorKind::DeserializeError(ResponseBody::new(status, path, body)),
+            source: Some(Box::new(e.into_inner())),
+        }
+    }
+
+    pub(crate) fn response(status: StatusCode, body: &[u8], e: serde_json::Error) -> Self {
+        Self {
+            kind: ErrorKind::ResponseError(ResponseBody::new(status, None, body)),
+            source: Some(Box::new(e)),
+        }
+    }
+}
+
+impl From<Ratelimit> for Error {
+    fn from(r: Ratelimit) -> Self {
+        Self {
+            kind: ErrorKind::RatelimitError(r),
+            source: None,
+        }
+    }
+}
+
 impl From<hyper::Error> for Error {
     fn from(e: hyper::Error) -> Self {
         Self {
@@ -67,6 +187,15 @@ impl From<hyper::Error> for Error {
     }
 }
 
+impl From<tokio::time::error::Elapsed> for Error {
+    fn from(e: tokio::time::error::Elapsed) -> Self {
+        Self {
+            kind: ErrorKind::TimeoutError,
+            source: Some(Box::new(e)),
+        }
+    }
+}
+
 impl From<hyper::http::Error> for Error {
     fn from(e: hyper::http::Error) -> Self {
         Self {
@@ -79,8 +208,83 @@ impl From<hyper::http::Error> for Error {
 /// The different kinds of errors that can be returned in pteroxide-http.
 #[derive(Debug)]
 pub enum ErrorKind {
-    DeserializeError,
-    RatelimitError,
+    DeserializeError(ResponseBody),
+    RatelimitError(Ratelimit),
     RequestError,
+    TimeoutError,
+    ResponseError(ResponseBody),
+    /// The server must be running to process the request. This is not returned when the node
+    /// is unreachable, which is a [`FractalError`][ErrorKind::FractalError] with the
+    /// `DaemonConnectionException` code instead.
+    ServerOfflineError,
+    WebSocketError,
     FractalError(FractalError),
 }
+
+/// Represents the details of a response body that could not be handled, used for debugging
+/// unexpected responses from the panel or a proxy in front of it.
+#[derive(Clone, Debug)]
+pub struct ResponseBody {
+    /// The HTTP status of the response.
+    pub status: StatusCode,
+    /// The path to the field that failed to be deserialized, if known.
+    pub path: Option<String>,
+    /// The start of the response body, truncated to 256 bytes.
+    pub snippet: String,
+}
+
+impl ResponseBody {
+    const SNIPPET_LENGTH: usize = 256;
+
+    pub(crate) fn new(status: StatusCode, path: Option<String>, body: &[u8]) -> Self {
+        let mut snippet = String::from_utf8_lossy(body).into_owned();
+        if snippet.len() > Self::SNIPPET_LENGTH {
+            let mut end = Self::SNIPPET_LENGTH;
+            while !snippet.is_char_boundary(end) {
+                end -= 1;
+            }
+            snippet.truncate(end);
+        }
+
+        Self {
+            status,
+            path,
+            snippet,
+        }
+    }
+}
+
+#[cfg(all(test, feature = "client"))]
+mod tests {
+    use pteroxide_models::fractal::{ErrorData, FractalError};
+
+    use super::{Error, ErrorKind};
+
+    fn fractal(code: &str, status: &str) -> Error {
+        FractalError {
+            errors: vec![ErrorData {
+                code: code.to_string(),
+                status: status.to_string(),
+                detail: String::new(),
+            }],
+        }
+        .into()
+    }
+
+    #[test]
+    fn converts_offline_server_errors() {
+        let err = fractal("HttpException", "502").server_offline();
+
+        assert!(matches!(err.kind(), ErrorKind::ServerOfflineError));
+        assert!(err.into_source().unwrap().is::<FractalError>());
+    }
+
+    #[test]
+    fn keeps_daemon_connection_errors() {
+        let err = fractal("DaemonConnectionException", "502").server_offline();
+        assert!(matches!(err.kind(), ErrorKind::FractalError(_)));
+
+        let err = fractal("HttpException", "404").server_offline();
+        assert!(matches!(err.kind(), ErrorKind::FractalError(_)));
+    }
+}
@@ -0,0 +1,305 @@
+use hyper::{
+    body::Bytes,
+    header::{HeaderValue, ACCEPT, AUTHORIZATION, CONTENT_TYPE, USER_AGENT},
+    HeaderMap, Request, Response, StatusCode,
+};
+use pteroxide_models::fractal::FractalError;
+use serde::Deserialize;
+use serde_json::{self, Value};
+use std::time::Duration;
+use tokio::time;
+
+#[cfg(feature = "native-tls")]
+use crate::transport::HttpConfig;
+use crate::{
+    error::*,
+    ratelimit::{Ratelimit, RetryPolicy},
+    transport::Transport,
+    Builder,
+};
+
+/// The shared HTTP core of the API interfaces, handling authentication, timeouts, ratelimits and
+/// decoding responses.
+#[derive(Debug)]
+pub(crate) struct Http {
+    pub(crate) transport: Box<dyn Transport>,
+    pub(crate) url: String,
+    pub(crate) key: String,
+    pub(crate) user_agent: HeaderValue,
+    pub(crate) headers: HeaderMap,
+    pub(crate) timeout: Option<Duration>,
+    pub(crate) retry_policy: Option<RetryPolicy>,
+}
+
+impl Http {
+    pub(crate) fn new(
+        url: String,
+        mut key: String,
+        transport: Box<dyn Transport>,
+        user_agent: &'static str,
+    ) -> Self {
+        if !key.starts_with("Bearer ") {
+            key.insert_str(0, "Bearer ");
+        }
+
+        Self {
+            transport,
+            url,
+            key,
+            user_agent: HeaderValue::from_static(user_agent),
+            headers: HeaderMap::new(),
+            timeout: None,
+            retry_policy: None,
+        }
+    }
+
+    /// Sends the request, retrying it if it is ratelimited and a [`RetryPolicy`] is set. Returns
+    /// the response regardless of the status unless it is ratelimited.
+    pub(crate) async fn send(&self, mut builder: Builder) -> Result<Response<Bytes>, Error> {
+        let uri = builder.uri(self.url.clone()); // problematic
+        let mut attempt = 0;
+
+        loop {
+            let mut req = Request::builder()
+                .uri(&uri)
+                .method(builder.method.clone())
+                .body(builder.body.clone())?;
+
+            let headers = req.headers_mut();
+            headers.extend(self.headers.clone());
+            headers.insert(USER_AGENT, self.user_agent.clone());
+            headers.insert(
+                AUTHORIZATION,
+                HeaderValue::from_str(&self.key).map_err(Error::request)?,
+            );
+            headers.insert(CONTENT_TYPE, builder.content_type.clone());
+            headers.insert(ACCEPT, builder.accept_type.clone());
+
+            let res = match self.timeout {
+                Some(t) => time::timeout(t, self.transport.send(req)).await??,
+                None => self.transport.send(req).await?,
+            };
+            if res.status() != StatusCode::TOO_MANY_REQUESTS {
+                return Ok(res);
+            }
+
+            let ratelimit = Ratelimit::from_headers(res.headers());
+            match self
+                .retry_policy
+                .as_ref()
+                .and_then(|p| p.delay(attempt, &ratelimit))
+            {
+                Some(delay) => {
+                    time::sleep(delay).await;
+                    attempt += 1;
+                }
+                None => return Err(Error::from(ratelimit)),
+            }
+        }
+    }
+
+    /// Sends the request and deserializes the response body into the model.
+    pub(crate) async fn request<T>(&self, builder: Builder) -> Result<T, Error>
+    where
+        for<'de> T: Deserialize<'de>,
+    {
+        let res = self.send(builder).await?;
+        let status = res.status();
+        let buf = res.into_body();
+
+        match status {
+            StatusCode::OK | StatusCode::CREATED | StatusCode::ACCEPTED => {
+                let mut de = serde_json::Deserializer::from_slice(&buf);
+
+                serde_path_to_error::deserialize(&mut de)
+                    .map_err(|e| Error::deserialize(status, &buf, e))
+            }
+            StatusCode::NO_CONTENT => serde_path_to_error::deserialize(Value::Null)
+                .map_err(|e| Error::deserialize(status, &[], e)),
+            _ => Err(Self::error(status, &buf)),
+        }
+    }
+
+    /// Sends the request and returns the raw response body, for endpoints that do not respond
+    /// with JSON.
+    #[cfg(any(feature = "client", feature = "wings"))]
+    pub(crate) async fn request_raw(&self, builder: Builder) -> Result<Bytes, Error> {
+        let res = self.send(builder).await?;
+        let status = res.status();
+        let buf = res.into_body();
+
+        if status.is_success() {
+            Ok(buf)
+        } else {
+            Err(Self::error(status, &buf))
+        }
+    }
+
+    fn error(status: StatusCode, buf: &[u8]) -> Error {
+        match serde_json::from_slice::<FractalError>(buf) {
+            Ok(data) => Error::from(data),
+            Err(e) => Error::response(status, buf, e),
+        }
+    }
+}
+
+/// The shared configuration of the API interface builders.
+#[derive(Debug)]
+pub(crate) struct HttpBuilder {
+    pub(crate) url: String,
+    pub(crate) key: String,
+    pub(crate) user_agent: String,
+    pub(crate) headers: HeaderMap,
+    pub(crate) timeout: Option<Duration>,
+    pub(crate) retry_policy: Option<RetryPolicy>,
+    pub(crate) transport: Option<Box<dyn Transport>>,
+    #[cfg(feature = "native-tls")]
+    pub(crate) config: HttpConfig,
+}
+
+impl HttpBuilder {
+    pub(crate) fn new(url: String, key: String, user_agent: &str) -> Self {
+        Self {
+            url,
+            key,
+            user_agent: user_agent.to_string(),
+            headers: HeaderMap::new(),
+            timeout: None,
+            retry_policy: None,
+            transport: None,
+            #[cfg(feature = "native-tls")]
+            config: Default::default(),
+        }
+    }
+
+    pub(crate) fn build(self) -> Result<Http, Error> {
+        let user_agent = HeaderValue::from_str(&self.user_agent).map_err(Error::request)?;
+        let transport = match self.transport {
+            Some(t) => t,
+            #[cfg(feature = "native-tls")]
+            None => self.config.build()?,
+            #[cfg(not(feature = "native-tls"))]
+            None => return Err(Error::request("no transport set for the interface")),
+        };
+
+        let mut http = Http::new(self.url, self.key, transport, "");
+        http.user_agent = user_agent;
+        http.headers = self.headers;
+        http.timeout = self.timeout;
+        http.retry_policy = self.retry_policy;
+
+        Ok(http)
+    }
+}
+
+/// Implements the shared configuration methods for an interface builder wrapping a
+/// [`HttpBuilder`].
+macro_rules! impl_http_builder {
+    ($type:ident, $agent:literal) => {
+        impl $type {
+            #[doc = concat!(
+                        "Sets the `User-Agent` header sent with every request. Defaults to\n`",
+                        $agent,
+                        "`."
+                    )]
+            pub fn user_agent(mut self, agent: &str) -> Self {
+                self.0.user_agent = agent.to_string();
+
+                self
+            }
+
+            /// Sets a header to send with every request. Headers set by the request itself, such
+            /// as `Authorization` and `Content-Type`, take priority over these.
+            pub fn header(
+                mut self,
+                name: ::hyper::header::HeaderName,
+                value: ::hyper::header::HeaderValue,
+            ) -> Self {
+                self.0.headers.insert(name, value);
+
+                self
+            }
+
+            /// Sets the timeout for each request, including reading the response body. Defaults
+            /// to [`None`] - no timeout.
+            pub fn timeout(mut self, timeout: ::std::time::Duration) -> Self {
+                self.0.timeout = Some(timeout);
+
+                self
+            }
+
+            /// Sets the [`RetryPolicy`][crate::RetryPolicy] to use when a request is ratelimited.
+            /// Defaults to [`None`] - ratelimited requests are not retried.
+            pub fn retry_policy(mut self, policy: $crate::RetryPolicy) -> Self {
+                self.0.retry_policy = Some(policy);
+
+                self
+            }
+
+            /// Sets the timeout for connecting to the panel. Defaults to [`None`] - no timeout.
+            #[cfg(feature = "native-tls")]
+            pub fn connect_timeout(mut self, timeout: ::std::time::Duration) -> Self {
+                self.0.config.connect_timeout = Some(timeout);
+
+                self
+            }
+
+            /// Adds a trusted root [`Certificate`][crate::transport::Certificate] for connecting
+            /// to the panel, such as the certificate of an internal certificate authority.
+            #[cfg(feature = "native-tls")]
+            pub fn root_certificate(mut self, cert: $crate::transport::Certificate) -> Self {
+                self.0.config.root_certificates.push(cert);
+
+                self
+            }
+
+            /// Whether invalid certificates (including self-signed and expired certificates)
+            /// should be accepted. Defaults to `false`.
+            ///
+            /// ### Warning
+            ///
+            /// This disables certificate validation entirely, allowing anyone in the middle to
+            /// read or modify requests, including the API key. Prefer adding the certificate with
+            /// [`root_certificate`][Self::root_certificate] instead.
+            #[cfg(feature = "native-tls")]
+            pub fn danger_accept_invalid_certs(mut self, value: bool) -> Self {
+                self.0.config.accept_invalid_certs = value;
+
+                self
+            }
+
+            /// Sets a HTTP proxy to send all requests through. Requests are tunneled through the
+            /// proxy using the `CONNECT` method.
+            #[cfg(feature = "native-tls")]
+            pub fn proxy(mut self, uri: ::hyper::Uri) -> Self {
+                self.0.config.proxy = Some(uri);
+
+                self
+            }
+
+            /// Sets the credentials for authenticating with the proxy using basic
+            /// authentication.
+            #[cfg(feature = "native-tls")]
+            pub fn proxy_auth(mut self, username: &str, password: &str) -> Self {
+                self.0.config.proxy_auth = Some((username.to_string(), password.to_string()));
+
+                self
+            }
+
+            /// Sets a custom [`Transport`][crate::Transport] to send requests through. This
+            /// overrides the default hyper client, so the connection options (timeouts,
+            /// certificates and proxy) set in the builder have no effect apart from the request
+            /// timeout.
+            pub fn transport<T>(mut self, transport: T) -> Self
+            where
+                T: $crate::Transport + 'static,
+            {
+                self.0.transport = Some(Box::new(transport));
+
+                self
+            }
+        }
+    };
+}
+
+pub(crate) use impl_http_builder;
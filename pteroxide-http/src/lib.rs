@@ -4,8 +4,26 @@
 
 pub mod application;
 pub mod builder;
+#[cfg(feature = "client")]
+pub mod client;
 pub mod error;
+mod http;
+pub mod query;
+pub mod ratelimit;
 pub mod response;
 pub mod routing;
+pub mod transport;
+#[cfg(feature = "wings")]
+pub mod wings;
 
-pub use self::{application::Application, builder::Builder, error::Error};
+#[cfg(feature = "client")]
+pub use self::client::{builder::ClientBuilder, Client};
+#[cfg(feature = "wings")]
+pub use self::wings::{builder::WingsBuilder, Wings};
+pub use self::{
+    application::{builder::ApplicationBuilder, Application},
+    builder::Builder,
+    error::Error,
+    ratelimit::RetryPolicy,
+    transport::Transport,
+};
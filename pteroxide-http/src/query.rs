@@ -0,0 +1,205 @@
+/// The order to sort the objects of a list request in.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum SortOrder {
+    #[default]
+    Ascending,
+    Descending,
+}
+
+impl SortOrder {
+    /// Returns the value of the `sort` query parameter for the given field, which the panel
+    /// expects to be prefixed with `-` for descending order.
+    pub fn with_field(&self, field: &str) -> String {
+        match self {
+            SortOrder::Ascending => field.to_string(),
+            SortOrder::Descending => format!("-{}", field),
+        }
+    }
+}
+
+/// A filter for the users returned by [`GetUsers`]. Filters on text fields are partial matches.
+///
+/// [`GetUsers`]: crate::application::users::GetUsers
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum UserFilter<'a> {
+    Email(&'a str),
+    Uuid(&'a str),
+    Username(&'a str),
+    ExternalId(&'a str),
+}
+
+impl UserFilter<'_> {
+    pub(crate) fn param(&self) -> (&'static str, String) {
+        match *self {
+            Self::Email(v) => ("filter[email]", v.to_string()),
+            Self::Uuid(v) => ("filter[uuid]", v.to_string()),
+            Self::Username(v) => ("filter[username]", v.to_string()),
+            Self::ExternalId(v) => ("filter[external_id]", v.to_string()),
+        }
+    }
+}
+
+/// A field to sort the users returned by [`GetUsers`] by.
+///
+/// [`GetUsers`]: crate::application::users::GetUsers
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum UserSort {
+    Id,
+    Uuid,
+}
+
+impl UserSort {
+    pub(crate) const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Id => "id",
+            Self::Uuid => "uuid",
+        }
+    }
+}
+
+/// A filter for the servers returned by [`GetServers`]. Filters on text fields are partial
+/// matches.
+///
+/// [`GetServers`]: crate::application::servers::GetServers
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ServerFilter<'a> {
+    Name(&'a str),
+    Uuid(&'a str),
+    /// The short UUID (identifier) of the server.
+    UuidShort(&'a str),
+    ExternalId(&'a str),
+    /// The docker image of the server.
+    Image(&'a str),
+}
+
+impl ServerFilter<'_> {
+    pub(crate) fn param(&self) -> (&'static str, String) {
+        match *self {
+            Self::Name(v) => ("filter[name]", v.to_string()),
+            Self::Uuid(v) => ("filter[uuid]", v.to_string()),
+            Self::UuidShort(v) => ("filter[uuidShort]", v.to_string()),
+            Self::ExternalId(v) => ("filter[external_id]", v.to_string()),
+            Self::Image(v) => ("filter[image]", v.to_string()),
+        }
+    }
+}
+
+/// A field to sort the servers returned by [`GetServers`] by.
+///
+/// [`GetServers`]: crate::application::servers::GetServers
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ServerSort {
+    Id,
+    Uuid,
+}
+
+impl ServerSort {
+    pub(crate) const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Id => "id",
+            Self::Uuid => "uuid",
+        }
+    }
+}
+
+/// A filter for the nodes returned by [`GetNodes`]. Filters on text fields are partial matches.
+///
+/// [`GetNodes`]: crate::application::nodes::GetNodes
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum NodeFilter<'a> {
+    Name(&'a str),
+    Fqdn(&'a str),
+    Uuid(&'a str),
+}
+
+impl NodeFilter<'_> {
+    pub(crate) fn param(&self) -> (&'static str, String) {
+        match *self {
+            Self::Name(v) => ("filter[name]", v.to_string()),
+            Self::Fqdn(v) => ("filter[fqdn]", v.to_string()),
+            Self::Uuid(v) => ("filter[uuid]", v.to_string()),
+        }
+    }
+}
+
+/// A field to sort the nodes returned by [`GetNodes`] by.
+///
+/// [`GetNodes`]: crate::application::nodes::GetNodes
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum NodeSort {
+    Id,
+    Uuid,
+    Memory,
+    Disk,
+}
+
+impl NodeSort {
+    pub(crate) const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Id => "id",
+            Self::Uuid => "uuid",
+            Self::Memory => "memory",
+            Self::Disk => "disk",
+        }
+    }
+}
+
+/// A filter for the locations returned by [`GetLocations`]. Filters are partial matches.
+///
+/// [`GetLocations`]: crate::application::locations::GetLocations
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum LocationFilter<'a> {
+    /// The short code of the location.
+    Short(&'a str),
+    /// The long description of the location.
+    Long(&'a str),
+}
+
+impl LocationFilter<'_> {
+    pub(crate) fn param(&self) -> (&'static str, String) {
+        match *self {
+            Self::Short(v) => ("filter[short]", v.to_string()),
+            Self::Long(v) => ("filter[long]", v.to_string()),
+        }
+    }
+}
+
+/// A field to sort the locations returned by [`GetLocations`] by.
+///
+/// [`GetLocations`]: crate::application::locations::GetLocations
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum LocationSort {
+    Id,
+}
+
+impl LocationSort {
+    pub(crate) const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Id => "id",
+        }
+    }
+}
+
+/// A filter for the allocations returned by [`GetAllocations`]. The IP address and port filters
+/// are exact matches.
+///
+/// [`GetAllocations`]: crate::application::allocations::GetAllocations
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AllocationFilter<'a> {
+    Ip(&'a str),
+    Port(i32),
+    /// The ID of the server the allocation is assigned to, or [`None`] for allocations that are
+    /// not assigned to a server.
+    ServerId(Option<i32>),
+}
+
+impl AllocationFilter<'_> {
+    pub(crate) fn param(&self) -> (&'static str, String) {
+        match *self {
+            Self::Ip(v) => ("filter[ip]", v.to_string()),
+            Self::Port(v) => ("filter[port]", v.to_string()),
+            Self::ServerId(Some(v)) => ("filter[server_id]", v.to_string()),
+            Self::ServerId(None) => ("filter[server_id]", String::from("false")),
+        }
+    }
+}
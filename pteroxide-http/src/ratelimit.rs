@@ -0,0 +1,170 @@
+use hyper::HeaderMap;
+use std::time::Duration;
+
+/// Represents the ratelimit information sent by the panel in the response headers.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Ratelimit {
+    /// The maximum number of requests allowed per minute (`X-RateLimit-Limit`).
+    pub limit: Option<u32>,
+    /// The number of requests remaining for the current minute (`X-RateLimit-Remaining`).
+    pub remaining: Option<u32>,
+    /// The time to wait before sending another request (`Retry-After`).
+    pub retry_after: Option<Duration>,
+}
+
+impl Ratelimit {
+    /// Parses the ratelimit information from a set of response headers. Headers that are missing
+    /// or invalid are left unset.
+    pub fn from_headers(headers: &HeaderMap) -> Self {
+        let parse = |key: &str| {
+            headers
+                .get(key)
+                .and_then(|v| v.to_str().ok())
+                .and_then(|v| v.trim().parse::<u32>().ok())
+        };
+
+        Self {
+            limit: parse("x-ratelimit-limit"),
+            remaining: parse("x-ratelimit-remaining"),
+            retry_after: parse("retry-after").map(|v| Duration::from_secs(v.into())),
+        }
+    }
+}
+
+/// A policy for automatically retrying requests that were ratelimited by the panel. The delay
+/// between retries is taken from the `Retry-After` header if present, otherwise it doubles from
+/// the base delay on each attempt, capped at the max delay. If the panel asks to wait longer than
+/// the max delay, the request is not retried.
+#[derive(Clone, Debug)]
+pub struct RetryPolicy {
+    max_retries: u32,
+    base_delay: Duration,
+    max_delay: Duration,
+}
+
+impl RetryPolicy {
+    /// Creates a new retry policy with the given number of retries. The base delay defaults to
+    /// 1 second and the max delay defaults to 60 seconds.
+    pub const fn new(max_retries: u32) -> Self {
+        Self {
+            max_retries,
+            base_delay: Duration::from_secs(1),
+            max_delay: Duration::from_secs(60),
+        }
+    }
+
+    /// Sets the delay used for the first retry when the panel does not send a `Retry-After`
+    /// header.
+    pub const fn base_delay(mut self, delay: Duration) -> Self {
+        self.base_delay = delay;
+
+        self
+    }
+
+    /// Sets the maximum delay to wait between retries. A `Retry-After` longer than this ends the
+    /// retries instead of being shortened, as retrying earlier would only be ratelimited again.
+    pub const fn max_delay(mut self, delay: Duration) -> Self {
+        self.max_delay = delay;
+
+        self
+    }
+
+    /// Returns the delay to wait before the given retry attempt (starting at `0`), or [`None`] if
+    /// the retry limit has been reached or the `Retry-After` sent by the panel exceeds the max
+    /// delay.
+    pub fn delay(&self, attempt: u32, ratelimit: &Ratelimit) -> Option<Duration> {
+        if attempt >= self.max_retries {
+            return None;
+        }
+
+        match ratelimit.retry_after {
+            Some(d) if d > self.max_delay => None,
+            Some(d) => Some(d),
+            None => Some(
+                self.base_delay
+                    .saturating_mul(2u32.saturating_pow(attempt))
+                    .min(self.max_delay),
+            ),
+        }
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::new(3)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hyper::{header::HeaderValue, HeaderMap};
+    use std::time::Duration;
+
+    use super::{Ratelimit, RetryPolicy};
+
+    fn ratelimit(retry_after: Option<u64>) -> Ratelimit {
+        Ratelimit {
+            retry_after: retry_after.map(Duration::from_secs),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn parses_ratelimit_headers() {
+        let mut headers = HeaderMap::new();
+        headers.insert("x-ratelimit-limit", HeaderValue::from_static("240"));
+        headers.insert("x-ratelimit-remaining", HeaderValue::from_static(" 0 "));
+        headers.insert("retry-after", HeaderValue::from_static("17"));
+
+        assert_eq!(
+            Ratelimit::from_headers(&headers),
+            Ratelimit {
+                limit: Some(240),
+                remaining: Some(0),
+                retry_after: Some(Duration::from_secs(17)),
+            }
+        );
+    }
+
+    #[test]
+    fn ignores_missing_and_invalid_headers() {
+        let mut headers = HeaderMap::new();
+        headers.insert("x-ratelimit-limit", HeaderValue::from_static("lots"));
+        headers.insert("retry-after", HeaderValue::from_static("-1"));
+
+        assert_eq!(Ratelimit::from_headers(&headers), Ratelimit::default());
+    }
+
+    #[test]
+    fn backoff_doubles_and_is_capped() {
+        let policy = RetryPolicy::new(10)
+            .base_delay(Duration::from_secs(1))
+            .max_delay(Duration::from_secs(10));
+        let delays: Vec<_> = (0..6)
+            .map(|a| policy.delay(a, &ratelimit(None)).unwrap().as_secs())
+            .collect();
+
+        assert_eq!(delays, [1, 2, 4, 8, 10, 10]);
+        assert_eq!(
+            policy.delay(40, &ratelimit(None)),
+            None,
+            "attempts past the limit are not retried"
+        );
+    }
+
+    #[test]
+    fn honors_retry_after() {
+        let policy = RetryPolicy::new(3).max_delay(Duration::from_secs(30));
+
+        assert_eq!(
+            policy.delay(0, &ratelimit(Some(20))),
+            Some(Duration::from_secs(20))
+        );
+        assert_eq!(
+            policy.delay(2, &ratelimit(Some(30))),
+            Some(Duration::from_secs(30))
+        );
+        assert_eq!(policy.delay(0, &ratelimit(Some(31))), None);
+        assert_eq!(policy.delay(3, &ratelimit(Some(1))), None);
+    }
+}
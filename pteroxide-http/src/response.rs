@@ -1,87 +1,255 @@
-use pteroxide_models::fractal::FractalList;
+use futures_util::stream::{self, Stream};
+use pteroxide_models::fractal::{FractalList, FractalPagination};
 use serde::Deserialize;
-use std::marker::PhantomData;
+use std::{marker::PhantomData, vec::IntoIter};
 
-use crate::{routing::Route, Application, Builder, Error};
+use crate::{http::Http, routing::Route, Builder, Error};
 
+/// Represents a single page of results from a list endpoint, along with the pagination
+/// information returned by the panel.
+#[derive(Clone, Debug)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    pub pagination: FractalPagination,
+}
+
+impl<T> Page<T> {
+    /// Returns the total number of objects across all pages.
+    pub const fn total(&self) -> i32 {
+        self.pagination.total
+    }
+
+    /// Returns the number of the current page.
+    pub const fn current_page(&self) -> i32 {
+        self.pagination.current_page
+    }
+
+    /// Returns the total number of pages available.
+    pub const fn total_pages(&self) -> i32 {
+        self.pagination.total_pages
+    }
+
+    /// Returns `true` if there are more pages after the current one.
+    pub const fn has_next_page(&self) -> bool {
+        self.pagination.current_page < self.pagination.total_pages
+    }
+}
+
+/// A paginated request handle for list endpoints. This can fetch individual pages, collect every
+/// page into a single list, or lazily walk through the pages as a [`Stream`].
+#[derive(Debug)]
 pub struct Response<'a, T> {
     phantom: PhantomData<T>,
-    http: &'a Application,
+    http: &'a Http,
     route: Route,
     params: Vec<(String, String)>,
+    include: Vec<String>,
     page: u32,
     per_page: u32,
 }
 
 impl<'a, T> Response<'a, T> {
-    pub fn new(http: &'a Application, route: Route) -> Self {
+    pub(crate) fn new(http: &'a Http, route: Route) -> Self {
         Self {
             phantom: PhantomData,
             http,
             route,
             params: Default::default(),
+            include: Default::default(),
             page: 1,
             per_page: 50,
         }
     }
 
+    /// Sets the page to fetch. Defaults to `1`.
     pub fn page(mut self, page: u32) -> Self {
         self.page = page;
 
         self
     }
 
+    /// Sets the number of objects to fetch per page. Defaults to `50`.
     pub fn per_page(mut self, per_page: u32) -> Self {
         self.per_page = per_page;
 
         self
     }
 
-    pub async fn get_page(self) -> Result<Vec<T>, Error>
+    /// Sets a HTTP query parameter to include in every page request.
+    pub fn param(mut self, key: &str, value: &str) -> Self {
+        self.params.push((key.to_string(), value.to_string()));
+
+        self
+    }
+
+    /// Sets a relationship to include in every page request.
+    pub fn include(mut self, value: &str) -> Self {
+        self.include.push(value.to_string());
+
+        self
+    }
+
+    /// Asynchronously fetches the current page and returns it with its pagination information.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an [`Error`] if the request fails.
+    pub async fn get_page(&self) -> Result<Page<T>, Error>
     where
-        for<'de> T: Deserialize<'de> + Clone,
+        for<'de> T: Deserialize<'de>,
     {
-        let mut builder = Builder::new(self.route)
+        let mut builder = Builder::new(self.route.clone())
             .param("page", &self.page.to_string())
             .param("per_page", &self.per_page.to_string());
 
-        for (key, value) in self.params {
-            builder = builder.param(&key, &value);
+        for (key, value) in &self.params {
+            builder = builder.param(key, value);
+        }
+        for value in &self.include {
+            builder = builder.include(value);
         }
 
         let res = self.http.request::<FractalList<T>>(builder).await?;
-
-        Ok(res.data.iter().map(|v| v.attributes.clone()).collect())
+        let items: Vec<T> = res.data.into_iter().map(|v| v.attributes).collect();
+        let pagination = match res.meta {
+            Some(m) => m.pagination,
+            None => FractalPagination {
+                total: items.len() as i32,
+                count: items.len() as i32,
+                per_page: self.per_page as i32,
+                current_page: self.page as i32,
+                total_pages: self.page as i32,
+            },
+        };
+
+        Ok(Page { items, pagination })
     }
 
-    pub async fn get_next_page(mut self) -> Result<Vec<T>, Error>
+    /// Advances to the next page and asynchronously fetches it.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an [`Error`] if the request fails.
+    pub async fn get_next_page(&mut self) -> Result<Page<T>, Error>
     where
-        for<'de> T: Deserialize<'de> + Clone,
+        for<'de> T: Deserialize<'de>,
     {
         self.page += 1;
 
         self.get_page().await
     }
 
-    // pub async fn collect(mut self) -> Result<Vec<T>, Error>
-    // where
-    //     for<'de> T: Deserialize<'de> + Clone,
-    // {
-    //     let mut res = self.get_page().await?;
-
-    //     loop {
-    //         self.page += 1;
-
-    //         if let Ok(value) = self.get_page().await {
-    //             if value.len() == 0 {
-    //                 break;
-    //             }
-    //             res.extend(value);
-    //         } else {
-    //             break;
-    //         }
-    //     }
-
-    //     Ok(res)
-    // }
+    /// Asynchronously fetches every page starting from the current one and returns all the
+    /// objects in a single list.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an [`Error`] if any of the page requests fail.
+    pub async fn collect_all(mut self) -> Result<Vec<T>, Error>
+    where
+        for<'de> T: Deserialize<'de>,
+    {
+        let mut page = self.get_page().await?;
+        let mut res = Vec::with_capacity(page.total().max(0) as usize);
+
+        loop {
+            let next = page.has_next_page() && !page.items.is_empty();
+            res.extend(page.items);
+
+            if !next {
+                break;
+            }
+            page = self.get_next_page().await?;
+        }
+
+        Ok(res)
+    }
+
+    /// Returns a [`Stream`] that lazily fetches each page as the previous one is consumed,
+    /// yielding the objects one at a time. The stream ends after the last page or the first
+    /// error.
+    pub fn stream(self) -> impl Stream<Item = Result<T, Error>> + 'a
+    where
+        for<'de> T: Deserialize<'de> + 'a,
+    {
+        let state = StreamState {
+            res: self,
+            items: Vec::new().into_iter(),
+            started: false,
+            done: false,
+        };
+
+        stream::unfold(state, |mut state| async move {
+            loop {
+                if let Some(item) = state.items.next() {
+                    return Some((Ok(item), state));
+                }
+                if state.done {
+                    return None;
+                }
+                if state.started {
+                    state.res.page += 1;
+                }
+                state.started = true;
+
+                match state.res.get_page().await {
+                    Ok(page) => {
+                        state.done = !page.has_next_page() || page.items.is_empty();
+                        state.items = page.items.into_iter();
+                    }
+                    Err(e) => {
+                        state.done = true;
+                        return Some((Err(e), state));
+                    }
+                }
+            }
+        })
+    }
+}
+
+#[doc(hidden)]
+struct StreamState<'a, T> {
+    res: Response<'a, T>,
+    items: IntoIter<T>,
+    started: bool,
+    done: bool,
 }
+
+/// Implements the shared `page`, `per_page` and `exec` methods for a list request builder with
+/// `page` and `per_page` fields and a `paginate` method returning a [`Response`].
+macro_rules! impl_paginate {
+    ($type:ident, $model:ident, $name:literal) => {
+        impl<'a> $type<'a> {
+            #[doc = concat!("Sets the page of ", $name, " to fetch. Defaults to `1`.")]
+            pub fn page(mut self, page: u32) -> Self {
+                self.page = page;
+
+                self
+            }
+
+            #[doc = concat!("Sets the number of ", $name, " to fetch per page. Defaults to `50`.")]
+            pub fn per_page(mut self, per_page: u32) -> Self {
+                self.per_page = per_page;
+
+                self
+            }
+
+            #[doc = concat!(
+                                "Asynchronously executes the request and returns a list of [`",
+                                stringify!($model),
+                                "`] objects from the\ncurrent page."
+                            )]
+            ///
+            /// ## Errors
+            ///
+            /// Returns an [`Error`][crate::Error] if the request fails.
+            pub async fn exec(&self) -> Result<Vec<$model>, $crate::Error> {
+                let res = self.paginate().get_page().await?;
+
+                Ok(res.items)
+            }
+        }
+    };
+}
+
+pub(crate) use impl_paginate;
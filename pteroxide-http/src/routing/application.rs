@@ -1,16 +1,19 @@
 use hyper::Method;
+use urlencoding::encode;
 
 use super::Route;
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub enum Application {
     GetUsers,
     GetUser { id: i32 },
+    GetUserByExternalId { id: String },
     CreateUser,
     UpdateUser { id: i32 },
     DeleteUser { id: i32 },
     GetServers,
     GetServer { id: i32 },
+    GetServerByExternalId { id: String },
     CreateServer,
     UpdateServerBuild { id: i32 },
     UpdateServerDetails { id: i32 },
@@ -19,6 +22,11 @@ pub enum Application {
     UnsuspendServer { id: i32 },
     ReinstallServer { id: i32 },
     DeleteServer { id: i32, force: bool },
+    GetServerDatabases { id: i32 },
+    GetServerDatabase { id: i32, database: i32 },
+    CreateServerDatabase { id: i32 },
+    ResetServerDatabasePassword { id: i32, database: i32 },
+    DeleteServerDatabase { id: i32, database: i32 },
     GetNodes,
     GetNode { id: i32 },
     GetNodeConfig { id: i32 },
@@ -45,8 +53,12 @@ impl Application {
         match self {
             Application::GetUsers
             | Application::GetUser { .. }
+            | Application::GetUserByExternalId { .. }
             | Application::GetServers
             | Application::GetServer { .. }
+            | Application::GetServerByExternalId { .. }
+            | Application::GetServerDatabases { .. }
+            | Application::GetServerDatabase { .. }
             | Application::GetNodes
             | Application::GetNode { .. }
             | Application::GetNodeConfig { .. }
@@ -62,6 +74,8 @@ impl Application {
             | Application::SuspendServer { .. }
             | Application::UnsuspendServer { .. }
             | Application::ReinstallServer { .. }
+            | Application::CreateServerDatabase { .. }
+            | Application::ResetServerDatabasePassword { .. }
             | Application::CreateNode
             | Application::CreateLocation
             | Application::CreateAllocations { .. } => Method::POST,
@@ -73,6 +87,7 @@ impl Application {
             | Application::UpdateLocation { .. } => Method::PATCH,
             Application::DeleteUser { .. }
             | Application::DeleteServer { .. }
+            | Application::DeleteServerDatabase { .. }
             | Application::DeleteNode { .. }
             | Application::DeleteLocation { .. }
             | Application::DeleteAllocation { .. } => Method::DELETE,
@@ -80,6 +95,7 @@ impl Application {
     }
 }
 
+#[allow(clippy::to_string_trait_impl)]
 impl ToString for Application {
     fn to_string(&self) -> String {
         match self {
@@ -91,10 +107,16 @@ impl ToString for Application {
             | Application::DeleteUser { id } => {
                 format!("/api/application/users/{}", id)
             }
+            Application::GetUserByExternalId { id } => {
+                format!("/api/application/users/external/{}", encode(id))
+            }
             Application::GetServers | Application::CreateServer => {
                 String::from("/api/application/servers")
             }
             Application::GetServer { id } => format!("/api/application/servers/{}", id),
+            Application::GetServerByExternalId { id } => {
+                format!("/api/application/servers/external/{}", encode(id))
+            }
             Application::UpdateServerBuild { id } => {
                 format!("/api/application/servers/{}/build", id)
             }
@@ -118,6 +140,19 @@ impl ToString for Application {
                     format!("/api/application/servers/{}", id)
                 }
             }
+            Application::GetServerDatabases { id } | Application::CreateServerDatabase { id } => {
+                format!("/api/application/servers/{}/databases", id)
+            }
+            Application::GetServerDatabase { id, database }
+            | Application::DeleteServerDatabase { id, database } => {
+                format!("/api/application/servers/{}/databases/{}", id, database)
+            }
+            Application::ResetServerDatabasePassword { id, database } => {
+                format!(
+                    "/api/application/servers/{}/databases/{}/reset-password",
+                    id, database
+                )
+            }
             Application::GetNodes | Application::CreateNode => {
                 String::from("/api/application/nodes")
             }
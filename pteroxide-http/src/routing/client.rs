@@ -0,0 +1,410 @@
+use hyper::Method;
+
+use super::Route;
+
+#[derive(Clone, Debug)]
+pub enum Client {
+    GetAccount,
+    GetTwoFactor,
+    EnableTwoFactor,
+    DisableTwoFactor,
+    UpdateEmail,
+    UpdatePassword,
+    GetApiKeys,
+    CreateApiKey,
+    DeleteApiKey {
+        identifier: String,
+    },
+    GetServers,
+    GetServer {
+        id: String,
+    },
+    GetServerResources {
+        id: String,
+    },
+    SendPowerSignal {
+        id: String,
+    },
+    SendCommand {
+        id: String,
+    },
+    GetWebSocket {
+        id: String,
+    },
+    ListFiles {
+        id: String,
+    },
+    GetFileContents {
+        id: String,
+    },
+    GetFileDownload {
+        id: String,
+    },
+    RenameFiles {
+        id: String,
+    },
+    CopyFile {
+        id: String,
+    },
+    WriteFile {
+        id: String,
+    },
+    CompressFiles {
+        id: String,
+    },
+    DecompressFile {
+        id: String,
+    },
+    DeleteFiles {
+        id: String,
+    },
+    CreateFolder {
+        id: String,
+    },
+    ChmodFiles {
+        id: String,
+    },
+    PullFile {
+        id: String,
+    },
+    GetFileUpload {
+        id: String,
+    },
+    GetBackups {
+        id: String,
+    },
+    GetBackup {
+        id: String,
+        backup: String,
+    },
+    CreateBackup {
+        id: String,
+    },
+    GetBackupDownload {
+        id: String,
+        backup: String,
+    },
+    LockBackup {
+        id: String,
+        backup: String,
+    },
+    RestoreBackup {
+        id: String,
+        backup: String,
+    },
+    DeleteBackup {
+        id: String,
+        backup: String,
+    },
+    GetSchedules {
+        id: String,
+    },
+    GetSchedule {
+        id: String,
+        schedule: i32,
+    },
+    CreateSchedule {
+        id: String,
+    },
+    UpdateSchedule {
+        id: String,
+        schedule: i32,
+    },
+    ExecuteSchedule {
+        id: String,
+        schedule: i32,
+    },
+    DeleteSchedule {
+        id: String,
+        schedule: i32,
+    },
+    CreateTask {
+        id: String,
+        schedule: i32,
+    },
+    UpdateTask {
+        id: String,
+        schedule: i32,
+        task: i32,
+    },
+    DeleteTask {
+        id: String,
+        schedule: i32,
+        task: i32,
+    },
+    GetPermissions,
+    GetSubUsers {
+        id: String,
+    },
+    GetSubUser {
+        id: String,
+        user: String,
+    },
+    CreateSubUser {
+        id: String,
+    },
+    UpdateSubUser {
+        id: String,
+        user: String,
+    },
+    DeleteSubUser {
+        id: String,
+        user: String,
+    },
+    GetDatabases {
+        id: String,
+    },
+    CreateDatabase {
+        id: String,
+    },
+    RotateDatabasePassword {
+        id: String,
+        database: String,
+    },
+    DeleteDatabase {
+        id: String,
+        database: String,
+    },
+    GetAllocations {
+        id: String,
+    },
+    AssignAllocation {
+        id: String,
+    },
+    SetAllocationNotes {
+        id: String,
+        allocation: i32,
+    },
+    SetPrimaryAllocation {
+        id: String,
+        allocation: i32,
+    },
+    UnassignAllocation {
+        id: String,
+        allocation: i32,
+    },
+    GetStartup {
+        id: String,
+    },
+    UpdateStartupVariable {
+        id: String,
+    },
+    RenameServer {
+        id: String,
+    },
+    ReinstallServer {
+        id: String,
+    },
+    UpdateDockerImage {
+        id: String,
+    },
+    GetAccountActivity,
+    GetServerActivity {
+        id: String,
+    },
+}
+
+impl Client {
+    /// Returns the corresponding method for the current route.
+    pub fn method(&self) -> Method {
+        match self {
+            Client::GetAccount
+            | Client::GetTwoFactor
+            | Client::GetApiKeys
+            | Client::GetServers
+            | Client::GetServer { .. }
+            | Client::GetServerResources { .. }
+            | Client::GetWebSocket { .. }
+            | Client::ListFiles { .. }
+            | Client::GetFileContents { .. }
+            | Client::GetFileDownload { .. }
+            | Client::GetFileUpload { .. }
+            | Client::GetBackups { .. }
+            | Client::GetBackup { .. }
+            | Client::GetBackupDownload { .. }
+            | Client::GetSchedules { .. }
+            | Client::GetSchedule { .. }
+            | Client::GetPermissions
+            | Client::GetSubUsers { .. }
+            | Client::GetSubUser { .. }
+            | Client::GetDatabases { .. }
+            | Client::GetAllocations { .. }
+            | Client::GetStartup { .. }
+            | Client::GetAccountActivity
+            | Client::GetServerActivity { .. } => Method::GET,
+            Client::EnableTwoFactor
+            | Client::DisableTwoFactor
+            | Client::CreateApiKey
+            | Client::SendPowerSignal { .. }
+            | Client::SendCommand { .. }
+            | Client::CopyFile { .. }
+            | Client::WriteFile { .. }
+            | Client::CompressFiles { .. }
+            | Client::DecompressFile { .. }
+            | Client::DeleteFiles { .. }
+            | Client::CreateFolder { .. }
+            | Client::ChmodFiles { .. }
+            | Client::PullFile { .. }
+            | Client::CreateBackup { .. }
+            | Client::LockBackup { .. }
+            | Client::RestoreBackup { .. }
+            | Client::CreateSchedule { .. }
+            | Client::UpdateSchedule { .. }
+            | Client::ExecuteSchedule { .. }
+            | Client::CreateTask { .. }
+            | Client::UpdateTask { .. }
+            | Client::CreateSubUser { .. }
+            | Client::UpdateSubUser { .. }
+            | Client::CreateDatabase { .. }
+            | Client::RotateDatabasePassword { .. }
+            | Client::AssignAllocation { .. }
+            | Client::SetAllocationNotes { .. }
+            | Client::SetPrimaryAllocation { .. }
+            | Client::RenameServer { .. }
+            | Client::ReinstallServer { .. } => Method::POST,
+            Client::UpdateEmail
+            | Client::UpdatePassword
+            | Client::RenameFiles { .. }
+            | Client::UpdateStartupVariable { .. }
+            | Client::UpdateDockerImage { .. } => Method::PUT,
+            Client::DeleteApiKey { .. }
+            | Client::DeleteBackup { .. }
+            | Client::DeleteSchedule { .. }
+            | Client::DeleteTask { .. }
+            | Client::DeleteSubUser { .. }
+            | Client::DeleteDatabase { .. }
+            | Client::UnassignAllocation { .. } => Method::DELETE,
+        }
+    }
+}
+
+#[allow(clippy::to_string_trait_impl)]
+impl ToString for Client {
+    fn to_string(&self) -> String {
+        match self {
+            Client::GetAccount => String::from("/api/client/account"),
+            Client::GetTwoFactor | Client::EnableTwoFactor => {
+                String::from("/api/client/account/two-factor")
+            }
+            Client::DisableTwoFactor => String::from("/api/client/account/two-factor/disable"),
+            Client::UpdateEmail => String::from("/api/client/account/email"),
+            Client::UpdatePassword => String::from("/api/client/account/password"),
+            Client::GetApiKeys | Client::CreateApiKey => {
+                String::from("/api/client/account/api-keys")
+            }
+            Client::DeleteApiKey { identifier } => {
+                format!("/api/client/account/api-keys/{}", identifier)
+            }
+            Client::GetServers => String::from("/api/client"),
+            Client::GetServer { id } => format!("/api/client/servers/{}", id),
+            Client::GetServerResources { id } => {
+                format!("/api/client/servers/{}/resources", id)
+            }
+            Client::SendPowerSignal { id } => format!("/api/client/servers/{}/power", id),
+            Client::SendCommand { id } => format!("/api/client/servers/{}/command", id),
+            Client::GetWebSocket { id } => format!("/api/client/servers/{}/websocket", id),
+            Client::ListFiles { id } => format!("/api/client/servers/{}/files/list", id),
+            Client::GetFileContents { id } => format!("/api/client/servers/{}/files/contents", id),
+            Client::GetFileDownload { id } => format!("/api/client/servers/{}/files/download", id),
+            Client::RenameFiles { id } => format!("/api/client/servers/{}/files/rename", id),
+            Client::CopyFile { id } => format!("/api/client/servers/{}/files/copy", id),
+            Client::WriteFile { id } => format!("/api/client/servers/{}/files/write", id),
+            Client::CompressFiles { id } => format!("/api/client/servers/{}/files/compress", id),
+            Client::DecompressFile { id } => format!("/api/client/servers/{}/files/decompress", id),
+            Client::DeleteFiles { id } => format!("/api/client/servers/{}/files/delete", id),
+            Client::CreateFolder { id } => {
+                format!("/api/client/servers/{}/files/create-folder", id)
+            }
+            Client::ChmodFiles { id } => format!("/api/client/servers/{}/files/chmod", id),
+            Client::PullFile { id } => format!("/api/client/servers/{}/files/pull", id),
+            Client::GetFileUpload { id } => format!("/api/client/servers/{}/files/upload", id),
+            Client::GetBackups { id } | Client::CreateBackup { id } => {
+                format!("/api/client/servers/{}/backups", id)
+            }
+            Client::GetBackup { id, backup } | Client::DeleteBackup { id, backup } => {
+                format!("/api/client/servers/{}/backups/{}", id, backup)
+            }
+            Client::GetBackupDownload { id, backup } => {
+                format!("/api/client/servers/{}/backups/{}/download", id, backup)
+            }
+            Client::LockBackup { id, backup } => {
+                format!("/api/client/servers/{}/backups/{}/lock", id, backup)
+            }
+            Client::RestoreBackup { id, backup } => {
+                format!("/api/client/servers/{}/backups/{}/restore", id, backup)
+            }
+            Client::GetSchedules { id } | Client::CreateSchedule { id } => {
+                format!("/api/client/servers/{}/schedules", id)
+            }
+            Client::GetSchedule { id, schedule }
+            | Client::UpdateSchedule { id, schedule }
+            | Client::DeleteSchedule { id, schedule } => {
+                format!("/api/client/servers/{}/schedules/{}", id, schedule)
+            }
+            Client::ExecuteSchedule { id, schedule } => {
+                format!("/api/client/servers/{}/schedules/{}/execute", id, schedule)
+            }
+            Client::CreateTask { id, schedule } => {
+                format!("/api/client/servers/{}/schedules/{}/tasks", id, schedule)
+            }
+            Client::UpdateTask { id, schedule, task }
+            | Client::DeleteTask { id, schedule, task } => {
+                format!(
+                    "/api/client/servers/{}/schedules/{}/tasks/{}",
+                    id, schedule, task
+                )
+            }
+            Client::GetPermissions => String::from("/api/client/permissions"),
+            Client::GetSubUsers { id } | Client::CreateSubUser { id } => {
+                format!("/api/client/servers/{}/users", id)
+            }
+            Client::GetSubUser { id, user }
+            | Client::UpdateSubUser { id, user }
+            | Client::DeleteSubUser { id, user } => {
+                format!("/api/client/servers/{}/users/{}", id, user)
+            }
+            Client::GetDatabases { id } | Client::CreateDatabase { id } => {
+                format!("/api/client/servers/{}/databases", id)
+            }
+            Client::RotateDatabasePassword { id, database } => format!(
+                "/api/client/servers/{}/databases/{}/rotate-password",
+                id, database
+            ),
+            Client::DeleteDatabase { id, database } => {
+                format!("/api/client/servers/{}/databases/{}", id, database)
+            }
+            Client::GetAllocations { id } | Client::AssignAllocation { id } => {
+                format!("/api/client/servers/{}/network/allocations", id)
+            }
+            Client::SetAllocationNotes { id, allocation }
+            | Client::UnassignAllocation { id, allocation } => format!(
+                "/api/client/servers/{}/network/allocations/{}",
+                id, allocation
+            ),
+            Client::SetPrimaryAllocation { id, allocation } => format!(
+                "/api/client/servers/{}/network/allocations/{}/primary",
+                id, allocation
+            ),
+            Client::GetStartup { id } => format!("/api/client/servers/{}/startup", id),
+            Client::UpdateStartupVariable { id } => {
+                format!("/api/client/servers/{}/startup/variable", id)
+            }
+            Client::RenameServer { id } => format!("/api/client/servers/{}/settings/rename", id),
+            Client::ReinstallServer { id } => {
+                format!("/api/client/servers/{}/settings/reinstall", id)
+            }
+            Client::UpdateDockerImage { id } => {
+                format!("/api/client/servers/{}/settings/docker-image", id)
+            }
+            Client::GetAccountActivity => String::from("/api/client/account/activity"),
+            Client::GetServerActivity { id } => format!("/api/client/servers/{}/activity", id),
+        }
+    }
+}
+
+#[allow(clippy::from_over_into)]
+impl Into<Route> for Client {
+    fn into(self) -> Route {
+        Route::Client(self)
+    }
+}
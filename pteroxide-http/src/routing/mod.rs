@@ -1,24 +1,45 @@
 pub mod application;
+#[cfg(feature = "client")]
+pub mod client;
+#[cfg(feature = "wings")]
+pub mod wings;
 
 pub use application::Application;
+#[cfg(feature = "client")]
+pub use client::Client;
+#[cfg(feature = "wings")]
+pub use wings::Wings;
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub enum Route {
     Application(Application),
+    #[cfg(feature = "client")]
+    Client(Client),
+    #[cfg(feature = "wings")]
+    Wings(Wings),
 }
 
 impl Route {
     pub fn method(&self) -> hyper::Method {
         match self {
             Route::Application(r) => r.method(),
+            #[cfg(feature = "client")]
+            Route::Client(r) => r.method(),
+            #[cfg(feature = "wings")]
+            Route::Wings(r) => r.method(),
         }
     }
 }
 
+#[allow(clippy::to_string_trait_impl)]
 impl ToString for Route {
     fn to_string(&self) -> String {
         match self {
             Route::Application(r) => r.to_string(),
+            #[cfg(feature = "client")]
+            Route::Client(r) => r.to_string(),
+            #[cfg(feature = "wings")]
+            Route::Wings(r) => r.to_string(),
         }
     }
 }
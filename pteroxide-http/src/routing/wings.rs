@@ -0,0 +1,57 @@
+use hyper::Method;
+
+use super::Route;
+
+#[derive(Clone, Debug)]
+pub enum Wings {
+    GetSystemInformation,
+    GetServers,
+    GetServer { uuid: String },
+    SendPowerSignal { uuid: String },
+    SendCommands { uuid: String },
+    GetServerLogs { uuid: String },
+    InstallServer { uuid: String },
+    ReinstallServer { uuid: String },
+    SyncServer { uuid: String },
+}
+
+impl Wings {
+    /// Returns the corresponding method for the current route.
+    pub fn method(&self) -> Method {
+        match self {
+            Wings::GetSystemInformation
+            | Wings::GetServers
+            | Wings::GetServer { .. }
+            | Wings::GetServerLogs { .. } => Method::GET,
+            Wings::SendPowerSignal { .. }
+            | Wings::SendCommands { .. }
+            | Wings::InstallServer { .. }
+            | Wings::ReinstallServer { .. }
+            | Wings::SyncServer { .. } => Method::POST,
+        }
+    }
+}
+
+#[allow(clippy::to_string_trait_impl)]
+impl ToString for Wings {
+    fn to_string(&self) -> String {
+        match self {
+            Wings::GetSystemInformation => String::from("/api/system"),
+            Wings::GetServers => String::from("/api/servers"),
+            Wings::GetServer { uuid } => format!("/api/servers/{}", uuid),
+            Wings::SendPowerSignal { uuid } => format!("/api/servers/{}/power", uuid),
+            Wings::SendCommands { uuid } => format!("/api/servers/{}/commands", uuid),
+            Wings::GetServerLogs { uuid } => format!("/api/servers/{}/logs", uuid),
+            Wings::InstallServer { uuid } => format!("/api/servers/{}/install", uuid),
+            Wings::ReinstallServer { uuid } => format!("/api/servers/{}/reinstall", uuid),
+            Wings::SyncServer { uuid } => format!("/api/servers/{}/sync", uuid),
+        }
+    }
+}
+
+#[allow(clippy::from_over_into)]
+impl Into<Route> for Wings {
+    fn into(self) -> Route {
+        Route::Wings(self)
+    }
+}
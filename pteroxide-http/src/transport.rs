@@ -0,0 +1,128 @@
+#[cfg(feature = "native-tls")]
+use base64::{engine::general_purpose::STANDARD, Engine};
+use hyper::{
+    body::{self, Bytes},
+    client::connect::Connect,
+    Body, Client as HClient, Request, Response,
+};
+#[cfg(feature = "native-tls")]
+use hyper::{
+    client::HttpConnector,
+    header::{HeaderValue, PROXY_AUTHORIZATION},
+    Uri,
+};
+#[cfg(feature = "native-tls")]
+use hyper_proxy::{Intercept, Proxy, ProxyConnector};
+#[cfg(feature = "native-tls")]
+use hyper_tls::HttpsConnector;
+#[cfg(feature = "native-tls")]
+use native_tls::TlsConnector;
+#[cfg(feature = "native-tls")]
+use std::fmt::{Formatter, Result as FmtResult};
+#[cfg(feature = "native-tls")]
+use std::time::Duration;
+use std::{fmt::Debug, future::Future, pin::Pin};
+
+use crate::Error;
+
+#[cfg(feature = "native-tls")]
+pub use native_tls::Certificate;
+
+/// The future returned by a [`Transport`] when sending a request.
+pub type TransportFuture<'a> =
+    Pin<Box<dyn Future<Output = Result<Response<Bytes>, Error>> + Send + 'a>>;
+
+/// An abstraction over the HTTP client used to send requests to the panel. The request passed to
+/// the transport is fully built, including the URI, method, headers and body; the transport only
+/// needs to send it and return the response status, headers and body.
+///
+/// This is implemented for any hyper [`Client`][HClient], so a client using a different
+/// connector (such as rustls) can be used directly. Custom implementations can be used to mock
+/// responses in tests or route requests through an existing connection pool.
+pub trait Transport: Debug + Send + Sync {
+    /// Sends the request and returns the response with the body fully read.
+    fn send(&self, request: Request<Bytes>) -> TransportFuture<'_>;
+}
+
+impl<C> Transport for HClient<C, Body>
+where
+    C: Connect + Clone + Debug + Send + Sync + 'static,
+{
+    fn send(&self, request: Request<Bytes>) -> TransportFuture<'_> {
+        let req = request.map(Body::from);
+
+        Box::pin(async move {
+            let res = self.request(req).await?;
+            let (parts, body) = res.into_parts();
+            let buf = body::to_bytes(body).await?;
+
+            Ok(Response::from_parts(parts, buf))
+        })
+    }
+}
+
+/// The configuration for the default hyper [`Transport`], set through the interface builders.
+#[cfg(feature = "native-tls")]
+#[derive(Default)]
+pub(crate) struct HttpConfig {
+    pub(crate) connect_timeout: Option<Duration>,
+    pub(crate) root_certificates: Vec<Certificate>,
+    pub(crate) accept_invalid_certs: bool,
+    pub(crate) proxy: Option<Uri>,
+    pub(crate) proxy_auth: Option<(String, String)>,
+}
+
+#[cfg(feature = "native-tls")]
+impl Debug for HttpConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("HttpConfig")
+            .field("connect_timeout", &self.connect_timeout)
+            .field("root_certificates", &self.root_certificates.len())
+            .field("accept_invalid_certs", &self.accept_invalid_certs)
+            .field("proxy", &self.proxy)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(feature = "native-tls")]
+impl HttpConfig {
+    /// Builds a hyper client with a [`native-tls`](native_tls) connector from the configuration,
+    /// tunneling through the proxy if one is set.
+    pub(crate) fn build(self) -> Result<Box<dyn Transport>, Error> {
+        let mut http = HttpConnector::new();
+        http.enforce_http(false);
+        http.set_connect_timeout(self.connect_timeout);
+
+        let mut tls = TlsConnector::builder();
+        for cert in self.root_certificates {
+            tls.add_root_certificate(cert);
+        }
+        tls.danger_accept_invalid_certs(self.accept_invalid_certs);
+        let tls = tls.build().map_err(Error::request)?;
+
+        match self.proxy {
+            Some(uri) => {
+                let mut proxy = Proxy::new(Intercept::All, uri);
+                proxy.force_connect();
+
+                if let Some((username, password)) = self.proxy_auth {
+                    let auth = STANDARD.encode(format!("{}:{}", username, password));
+                    let value = HeaderValue::from_str(&format!("Basic {}", auth))
+                        .map_err(Error::request)?;
+
+                    proxy.set_header(PROXY_AUTHORIZATION, value);
+                }
+
+                let mut conn = ProxyConnector::from_proxy_unsecured(http, proxy);
+                conn.set_tls(Some(tls));
+
+                Ok(Box::new(HClient::builder().build::<_, Body>(conn)))
+            }
+            None => {
+                let conn = HttpsConnector::from((http, tls.into()));
+
+                Ok(Box::new(HClient::builder().build::<_, Body>(conn)))
+            }
+        }
+    }
+}
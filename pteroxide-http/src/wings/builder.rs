@@ -0,0 +1,31 @@
+use super::{Wings, DEFAULT_USER_AGENT};
+use crate::{
+    http::{impl_http_builder, HttpBuilder},
+    Error,
+};
+
+/// A builder for configuring a [`Wings`] interface, including the HTTP client, timeouts and
+/// headers sent with every request.
+#[derive(Debug)]
+pub struct WingsBuilder(HttpBuilder);
+
+impl WingsBuilder {
+    /// Creates a new builder with the given node URL and token.
+    pub fn new(url: String, key: String) -> Self {
+        Self(HttpBuilder::new(url, key, DEFAULT_USER_AGENT))
+    }
+
+    /// Builds the [`Wings`] interface.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an [`Error`] if the user agent is not a valid header value or if the HTTP client
+    /// fails to be built.
+    pub fn build(self) -> Result<Wings, Error> {
+        Ok(Wings {
+            http: self.0.build()?,
+        })
+    }
+}
+
+impl_http_builder!(WingsBuilder, "Pteroxide HTTP Wings");
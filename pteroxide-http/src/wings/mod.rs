@@ -0,0 +1,156 @@
+#[cfg(feature = "native-tls")]
+use hyper::Client as HClient;
+#[cfg(feature = "native-tls")]
+use hyper_tls::HttpsConnector;
+#[cfg(feature = "native-tls")]
+use pteroxide_models::application::NodeConfiguration;
+use pteroxide_models::PowerSignal;
+use serde::Deserialize;
+
+use self::{
+    builder::WingsBuilder,
+    servers::{
+        GetServer, GetServerLogs, GetServers, InstallServer, ReinstallServer, SendCommands,
+        SendPowerSignal, SyncServer,
+    },
+    system::GetSystemInformation,
+};
+use super::{error::*, http::Http, ratelimit::RetryPolicy, transport::Transport, Builder};
+
+pub mod builder;
+pub mod servers;
+pub mod signer;
+pub mod system;
+
+pub(crate) const DEFAULT_USER_AGENT: &str = "Pteroxide HTTP Wings";
+
+/// The main interface for interacting with the Wings API of a node directly, without going
+/// through the panel.
+///
+/// The URL is the address of the node daemon, such as `https://node.example.com:8080`, made up
+/// of the node [`scheme`], [`fqdn`] and [`daemon_listen`] port. The key is the node [`token`] on
+/// its own, which Wings checks against the `Authorization: Bearer` header. The
+/// `{token_id}.{token}` format is only used by Wings for its own requests to the panel.
+///
+/// [`scheme`]: pteroxide_models::application::Node::scheme
+/// [`fqdn`]: pteroxide_models::application::Node::fqdn
+/// [`daemon_listen`]: pteroxide_models::application::Node::daemon_listen
+/// [`token`]: pteroxide_models::application::NodeConfiguration::token
+#[derive(Debug)]
+pub struct Wings {
+    pub(crate) http: Http,
+}
+
+impl Wings {
+    /// Constructs a new [`Wings`] with the given node URL and token, using a hyper client with a
+    /// [`native-tls`](hyper_tls) connector.
+    #[cfg(feature = "native-tls")]
+    pub fn new(url: String, key: String) -> Self {
+        let conn = HttpsConnector::new();
+
+        Self::with_transport(url, key, HClient::builder().build(conn))
+    }
+
+    /// Constructs a new [`Wings`] with the given node URL, using the token from the
+    /// [`NodeConfiguration`] of the node.
+    #[cfg(feature = "native-tls")]
+    pub fn from_config(url: String, config: &NodeConfiguration) -> Self {
+        Self::new(url, config.token.clone())
+    }
+
+    /// Constructs a new [`Wings`] with the given node URL and token, sending requests through
+    /// the given [`Transport`].
+    pub fn with_transport<T>(url: String, key: String, transport: T) -> Self
+    where
+        T: Transport + 'static,
+    {
+        Self {
+            http: Http::new(url, key, Box::new(transport), DEFAULT_USER_AGENT),
+        }
+    }
+
+    /// Returns a [`WingsBuilder`] for configuring the interface with the given node URL and
+    /// token.
+    pub fn builder(url: String, key: String) -> WingsBuilder {
+        WingsBuilder::new(url, key)
+    }
+
+    /// Sets the [`RetryPolicy`] to use when a request is ratelimited and returns the interface.
+    /// By default, ratelimited requests are not retried and return a [`RatelimitError`].
+    ///
+    /// [`RatelimitError`]: ErrorKind::RatelimitError
+    pub fn retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.http.retry_policy = Some(policy);
+
+        self
+    }
+
+    /// Performs an API request using the [`Builder`] with the set fields. Returns a result with
+    /// the deserialized API response, if any.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an [`Error`] if the request fails, if the request is ratelimited and cannot be
+    /// retried, or if the response fails to be deserialized. Note that Wings does not return
+    /// Fractal errors, so API errors are returned as a [`ResponseError`] with the response body.
+    ///
+    /// [`ResponseError`]: ErrorKind::ResponseError
+    pub async fn request<T>(&self, builder: Builder) -> Result<T, Error>
+    where
+        for<'de> T: Deserialize<'de>,
+    {
+        self.http.request(builder).await
+    }
+
+    /// Returns a request builder for getting the [`SystemInformation`] of the node.
+    ///
+    /// [`SystemInformation`]: pteroxide_models::wings::SystemInformation
+    pub const fn get_system_information(&self) -> GetSystemInformation<'_> {
+        GetSystemInformation::new(self)
+    }
+
+    /// Returns a request builder for getting a list of the [`Server`]s on the node.
+    ///
+    /// [`Server`]: pteroxide_models::wings::Server
+    pub const fn get_servers(&self) -> GetServers<'_> {
+        GetServers::new(self)
+    }
+
+    /// Returns a request builder for getting a specified [`Server`] on the node by its UUID.
+    ///
+    /// [`Server`]: pteroxide_models::wings::Server
+    pub fn get_server(&self, uuid: &str) -> GetServer<'_> {
+        GetServer::new(self, uuid)
+    }
+
+    /// Returns a request builder for sending a [`PowerSignal`] to a server on the node.
+    pub fn send_power_signal(&self, uuid: &str, signal: PowerSignal) -> SendPowerSignal<'_> {
+        SendPowerSignal::new(self, uuid, signal)
+    }
+
+    /// Returns a request builder for sending commands to the console of a server on the node.
+    pub fn send_commands(&self, uuid: &str) -> SendCommands<'_> {
+        SendCommands::new(self, uuid)
+    }
+
+    /// Returns a request builder for getting the recent console output of a server on the node.
+    pub fn get_server_logs(&self, uuid: &str) -> GetServerLogs<'_> {
+        GetServerLogs::new(self, uuid)
+    }
+
+    /// Returns a request builder for running the installation script of a server on the node.
+    pub fn install_server(&self, uuid: &str) -> InstallServer<'_> {
+        InstallServer::new(self, uuid)
+    }
+
+    /// Returns a request builder for reinstalling a server on the node.
+    pub fn reinstall_server(&self, uuid: &str) -> ReinstallServer<'_> {
+        ReinstallServer::new(self, uuid)
+    }
+
+    /// Returns a request builder for syncing the configuration of a server on the node with the
+    /// panel.
+    pub fn sync_server(&self, uuid: &str) -> SyncServer<'_> {
+        SyncServer::new(self, uuid)
+    }
+}
@@ -0,0 +1,295 @@
+use pteroxide_models::{wings::Server, PowerSignal};
+use serde::{Deserialize, Serialize};
+
+use crate::{routing::Wings as Route, Builder, Error, Wings};
+
+#[derive(Debug)]
+pub struct GetServers<'a> {
+    wings: &'a Wings,
+}
+
+impl<'a> GetServers<'a> {
+    #[doc(hidden)]
+    pub const fn new(wings: &'a Wings) -> Self {
+        Self { wings }
+    }
+
+    /// Asynchronously executes the request and returns a list of the [`Server`] objects on the
+    /// node.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an [`Error`] if the request fails.
+    pub async fn exec(&self) -> Result<Vec<Server>, Error> {
+        self.wings
+            .request::<Vec<Server>>(Builder::new(Route::GetServers.into()))
+            .await
+    }
+}
+
+#[derive(Debug)]
+pub struct GetServer<'a> {
+    wings: &'a Wings,
+    uuid: String,
+}
+
+impl<'a> GetServer<'a> {
+    #[doc(hidden)]
+    pub fn new(wings: &'a Wings, uuid: &str) -> Self {
+        Self {
+            wings,
+            uuid: uuid.to_string(),
+        }
+    }
+
+    /// Asynchronously executes the request and returns a [`Server`] object.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an [`Error`] if the request fails or if the server is not found on the node.
+    pub async fn exec(&self) -> Result<Server, Error> {
+        let builder = Builder::new(
+            Route::GetServer {
+                uuid: self.uuid.clone(),
+            }
+            .into(),
+        );
+
+        self.wings.request::<Server>(builder).await
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct SendPowerSignalFields {
+    pub action: PowerSignal,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub wait_seconds: Option<u32>,
+}
+
+#[derive(Debug)]
+pub struct SendPowerSignal<'a> {
+    wings: &'a Wings,
+    uuid: String,
+    fields: SendPowerSignalFields,
+}
+
+impl<'a> SendPowerSignal<'a> {
+    #[doc(hidden)]
+    pub fn new(wings: &'a Wings, uuid: &str, signal: PowerSignal) -> Self {
+        Self {
+            wings,
+            uuid: uuid.to_string(),
+            fields: SendPowerSignalFields {
+                action: signal,
+                wait_seconds: None,
+            },
+        }
+    }
+
+    /// Sets the number of seconds to wait for another power action on the server to finish
+    /// before giving up. By default, the signal is dropped if another action is running.
+    pub fn wait_seconds(mut self, seconds: u32) -> Self {
+        self.fields.wait_seconds = Some(seconds);
+
+        self
+    }
+
+    /// Asynchronously executes the request. Note that the signal is processed in the
+    /// background, so the server has not changed its state when this returns.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an [`Error`] if the request fails or if the server is not found on the node.
+    pub async fn exec(self) -> Result<(), Error> {
+        let builder =
+            Builder::new(Route::SendPowerSignal { uuid: self.uuid }.into()).json(self.fields);
+
+        self.wings.http.request_raw(builder).await.map(|_| ())
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct SendCommandsFields<'a> {
+    pub commands: Vec<&'a str>,
+}
+
+#[derive(Debug)]
+pub struct SendCommands<'a> {
+    wings: &'a Wings,
+    uuid: String,
+    fields: SendCommandsFields<'a>,
+}
+
+impl<'a> SendCommands<'a> {
+    #[doc(hidden)]
+    pub fn new(wings: &'a Wings, uuid: &str) -> Self {
+        Self {
+            wings,
+            uuid: uuid.to_string(),
+            fields: SendCommandsFields {
+                commands: Vec::new(),
+            },
+        }
+    }
+
+    /// Adds a command to send to the server console. Commands are sent in the order they are
+    /// added.
+    pub fn command(mut self, command: &'a str) -> Self {
+        self.fields.commands.push(command);
+
+        self
+    }
+
+    /// Asynchronously executes the request.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an [`Error`] if the request fails, if the server is not found on the node or if
+    /// the server is offline.
+    pub async fn exec(self) -> Result<(), Error> {
+        let builder =
+            Builder::new(Route::SendCommands { uuid: self.uuid }.into()).json(self.fields);
+
+        self.wings.http.request_raw(builder).await.map(|_| ())
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct LogsResponse {
+    data: Vec<String>,
+}
+
+#[derive(Debug)]
+pub struct GetServerLogs<'a> {
+    wings: &'a Wings,
+    uuid: String,
+    size: Option<u32>,
+}
+
+impl<'a> GetServerLogs<'a> {
+    #[doc(hidden)]
+    pub fn new(wings: &'a Wings, uuid: &str) -> Self {
+        Self {
+            wings,
+            uuid: uuid.to_string(),
+            size: None,
+        }
+    }
+
+    /// Sets the number of lines to fetch from the end of the log (up to 100). Defaults to `100`.
+    pub fn size(mut self, size: u32) -> Self {
+        self.size = Some(size);
+
+        self
+    }
+
+    /// Asynchronously executes the request and returns the most recent lines of the server
+    /// console output.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an [`Error`] if the request fails or if the server is not found on the node.
+    pub async fn exec(&self) -> Result<Vec<String>, Error> {
+        let mut builder = Builder::new(
+            Route::GetServerLogs {
+                uuid: self.uuid.clone(),
+            }
+            .into(),
+        );
+        if let Some(s) = self.size {
+            builder = builder.param("size", &s.to_string());
+        }
+
+        let res = self.wings.request::<LogsResponse>(builder).await?;
+
+        Ok(res.data)
+    }
+}
+
+#[derive(Debug)]
+pub struct InstallServer<'a> {
+    wings: &'a Wings,
+    uuid: String,
+}
+
+impl<'a> InstallServer<'a> {
+    #[doc(hidden)]
+    pub fn new(wings: &'a Wings, uuid: &str) -> Self {
+        Self {
+            wings,
+            uuid: uuid.to_string(),
+        }
+    }
+
+    /// Asynchronously executes the request, running the installation script of the server.
+    /// Note that the server is installed in the background, so it is not complete when this
+    /// returns.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an [`Error`] if the request fails or if the server is not found on the node.
+    pub async fn exec(self) -> Result<(), Error> {
+        let builder = Builder::new(Route::InstallServer { uuid: self.uuid }.into());
+
+        self.wings.http.request_raw(builder).await.map(|_| ())
+    }
+}
+
+#[derive(Debug)]
+pub struct ReinstallServer<'a> {
+    wings: &'a Wings,
+    uuid: String,
+}
+
+impl<'a> ReinstallServer<'a> {
+    #[doc(hidden)]
+    pub fn new(wings: &'a Wings, uuid: &str) -> Self {
+        Self {
+            wings,
+            uuid: uuid.to_string(),
+        }
+    }
+
+    /// Asynchronously executes the request, syncing the server with the panel and running its
+    /// installation script again. Note that the server is reinstalled in the background, so it
+    /// is not complete when this returns.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an [`Error`] if the request fails, if the server is not found on the node or if
+    /// another power action is running on the server.
+    pub async fn exec(self) -> Result<(), Error> {
+        let builder = Builder::new(Route::ReinstallServer { uuid: self.uuid }.into());
+
+        self.wings.http.request_raw(builder).await.map(|_| ())
+    }
+}
+
+#[derive(Debug)]
+pub struct SyncServer<'a> {
+    wings: &'a Wings,
+    uuid: String,
+}
+
+impl<'a> SyncServer<'a> {
+    #[doc(hidden)]
+    pub fn new(wings: &'a Wings, uuid: &str) -> Self {
+        Self {
+            wings,
+            uuid: uuid.to_string(),
+        }
+    }
+
+    /// Asynchronously executes the request, making the node fetch the latest configuration of
+    /// the server from the panel.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an [`Error`] if the request fails, if the server is not found on the node or if
+    /// the node cannot reach the panel.
+    pub async fn exec(self) -> Result<(), Error> {
+        let builder = Builder::new(Route::SyncServer { uuid: self.uuid }.into());
+
+        self.wings.http.request_raw(builder).await.map(|_| ())
+    }
+}
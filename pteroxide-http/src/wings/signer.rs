@@ -0,0 +1,273 @@
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use hmac::{Hmac, Mac};
+use pteroxide_models::application::NodeConfiguration;
+use rand::{distributions::Alphanumeric, Rng};
+use serde::Serialize;
+use sha2::Sha256;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+#[derive(Serialize)]
+struct Header<'a> {
+    alg: &'static str,
+    typ: &'static str,
+    jti: &'a str,
+}
+
+#[derive(Serialize)]
+struct Claims<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    iss: Option<&'a str>,
+    aud: [&'a str; 1],
+    jti: &'a str,
+    iat: u64,
+    nbf: u64,
+    exp: u64,
+    server_uuid: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    file_path: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    backup_uuid: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    user_uuid: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    user_id: Option<i32>,
+    unique_id: &'a str,
+}
+
+/// Generates signed file download, backup download and file upload URLs for a node, the same way
+/// the panel does. The URLs contain a JSON Web Token signed with the node token, which Wings
+/// verifies without contacting the panel.
+///
+/// ## Example
+///
+/// ```no_run
+/// # use pteroxide_http::wings::signer::UrlSigner;
+/// let signer = UrlSigner::new("https://node.example.com:8080", "node-token");
+/// let url = signer.file_download("8d8e7fd4-0ba8-4bf0-a4a8-1fa0b6f4a7bd", "/logs/latest.log");
+/// println!("{}", url);
+/// ```
+#[derive(Clone, Debug)]
+pub struct UrlSigner {
+    url: String,
+    secret: String,
+    issuer: Option<String>,
+    user: Option<(String, i32)>,
+    expires_in: Duration,
+}
+
+impl UrlSigner {
+    /// Creates a new signer for the node at the given URL, using the node token (not the token
+    /// ID) as the signing secret.
+    pub fn new(url: &str, secret: &str) -> Self {
+        Self {
+            url: url.trim_end_matches('/').to_string(),
+            secret: secret.to_string(),
+            issuer: None,
+            user: None,
+            expires_in: Duration::from_secs(15 * 60),
+        }
+    }
+
+    /// Creates a new signer for the node at the given URL, using the token from the
+    /// [`NodeConfiguration`] of the node and the panel URL as the issuer.
+    pub fn from_config(url: &str, config: &NodeConfiguration) -> Self {
+        Self::new(url, &config.token).issuer(&config.remote)
+    }
+
+    /// Sets the issuer of the tokens, which is the panel URL. Defaults to [`None`].
+    pub fn issuer(mut self, issuer: &str) -> Self {
+        self.issuer = Some(issuer.to_string());
+
+        self
+    }
+
+    /// Sets the user the tokens are generated for, which Wings records in the server activity
+    /// logs. Defaults to [`None`].
+    pub fn user(mut self, uuid: &str, id: i32) -> Self {
+        self.user = Some((uuid.to_string(), id));
+
+        self
+    }
+
+    /// Sets how long the URLs are valid for. Defaults to 15 minutes, the same as the panel.
+    pub fn expires_in(mut self, duration: Duration) -> Self {
+        self.expires_in = duration;
+
+        self
+    }
+
+    /// Returns a signed URL for downloading a file from a server. The URL can only be used once.
+    pub fn file_download(&self, server_uuid: &str, file_path: &str) -> String {
+        let token = self.sign(server_uuid, Some(file_path), None);
+
+        format!("{}/download/file?token={}", self.url, token)
+    }
+
+    /// Returns a signed URL for downloading a backup of a server. The URL can only be used
+    /// once.
+    pub fn backup_download(&self, server_uuid: &str, backup_uuid: &str) -> String {
+        let token = self.sign(server_uuid, None, Some(backup_uuid));
+
+        format!("{}/download/backup?token={}", self.url, token)
+    }
+
+    /// Returns a signed URL for uploading files to a server. Files are uploaded with a
+    /// `multipart/form-data` POST request to the URL, with the target directory set in the
+    /// `directory` query parameter.
+    pub fn file_upload(&self, server_uuid: &str) -> String {
+        let token = self.sign(server_uuid, None, None);
+
+        format!("{}/upload/file?token={}", self.url, token)
+    }
+
+    fn sign(
+        &self,
+        server_uuid: &str,
+        file_path: Option<&str>,
+        backup_uuid: Option<&str>,
+    ) -> String {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        let jti = random_string(32);
+        let unique_id = random_string(16);
+
+        let header = Header {
+            alg: "HS256",
+            typ: "JWT",
+            jti: &jti,
+        };
+        let claims = Claims {
+            iss: self.issuer.as_deref(),
+            aud: [&self.url],
+            jti: &jti,
+            iat: now,
+            // the panel allows for some clock drift between itself and the node
+            nbf: now.saturating_sub(5 * 60),
+            exp: now + self.expires_in.as_secs(),
+            server_uuid,
+            file_path,
+            backup_uuid,
+            user_uuid: self.user.as_ref().map(|u| u.0.as_str()),
+            user_id: self.user.as_ref().map(|u| u.1),
+            unique_id: &unique_id,
+        };
+
+        // serializing these structs cannot fail
+        let header = URL_SAFE_NO_PAD.encode(serde_json::to_vec(&header).unwrap_or_default());
+        let claims = URL_SAFE_NO_PAD.encode(serde_json::to_vec(&claims).unwrap_or_default());
+        let payload = format!("{}.{}", header, claims);
+
+        let mut mac = Hmac::<Sha256>::new_from_slice(self.secret.as_bytes())
+            .expect("HMAC accepts keys of any length");
+        mac.update(payload.as_bytes());
+        let signature = URL_SAFE_NO_PAD.encode(mac.finalize().into_bytes());
+
+        format!("{}.{}", payload, signature)
+    }
+}
+
+fn random_string(len: usize) -> String {
+    rand::thread_rng()
+        .sample_iter(&Alphanumeric)
+        .take(len)
+        .map(char::from)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+    use hmac::{Hmac, Mac};
+    use serde_json::Value;
+    use sha2::Sha256;
+    use std::time::Duration;
+
+    use super::UrlSigner;
+
+    const SERVER: &str = "8d8e7fd4-0ba8-4bf0-a4a8-1fa0b6f4a7bd";
+
+    fn decode(part: &str) -> Value {
+        serde_json::from_slice(&URL_SAFE_NO_PAD.decode(part).unwrap()).unwrap()
+    }
+
+    // splits the token from the URL and verifies its signature with the secret
+    fn verify(url: &str, prefix: &str, secret: &str) -> (Value, Value) {
+        let token = url.strip_prefix(prefix).unwrap();
+        let (payload, signature) = token.rsplit_once('.').unwrap();
+
+        let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes()).unwrap();
+        mac.update(payload.as_bytes());
+        mac.verify_slice(&URL_SAFE_NO_PAD.decode(signature).unwrap())
+            .unwrap();
+
+        let (header, claims) = payload.split_once('.').unwrap();
+
+        (decode(header), decode(claims))
+    }
+
+    #[test]
+    fn signs_file_downloads() {
+        let signer = UrlSigner::new("https://node.example.com:8080/", "secret")
+            .issuer("https://panel.example.com")
+            .user("c4022c6c-9bf1-4a23-bff9-519cceb38335", 1)
+            .expires_in(Duration::from_secs(60));
+        let url = signer.file_download(SERVER, "/logs/latest.log");
+
+        let (header, claims) = verify(
+            &url,
+            "https://node.example.com:8080/download/file?token=",
+            "secret",
+        );
+        assert_eq!(header["alg"], "HS256");
+        assert_eq!(header["typ"], "JWT");
+        assert_eq!(header["jti"], claims["jti"]);
+
+        assert_eq!(claims["aud"][0], "https://node.example.com:8080");
+        assert_eq!(claims["iss"], "https://panel.example.com");
+        assert_eq!(claims["server_uuid"], SERVER);
+        assert_eq!(claims["file_path"], "/logs/latest.log");
+        assert_eq!(claims["user_uuid"], "c4022c6c-9bf1-4a23-bff9-519cceb38335");
+        assert_eq!(claims["user_id"], 1);
+        assert_eq!(claims["unique_id"].as_str().unwrap().len(), 16);
+        assert!(claims.get("backup_uuid").is_none());
+
+        let iat = claims["iat"].as_u64().unwrap();
+        assert_eq!(claims["nbf"].as_u64().unwrap(), iat - 5 * 60);
+        assert_eq!(claims["exp"].as_u64().unwrap(), iat + 60);
+    }
+
+    #[test]
+    fn signs_backup_downloads() {
+        let signer = UrlSigner::new("https://node.example.com:8080", "secret");
+        let url = signer.backup_download(SERVER, "0b5c5c4a-1f4e-4e55-8d0e-5d0e0d9e6f0a");
+
+        let (_, claims) = verify(
+            &url,
+            "https://node.example.com:8080/download/backup?token=",
+            "secret",
+        );
+        assert_eq!(claims["server_uuid"], SERVER);
+        assert_eq!(
+            claims["backup_uuid"],
+            "0b5c5c4a-1f4e-4e55-8d0e-5d0e0d9e6f0a"
+        );
+        assert!(claims.get("iss").is_none());
+        assert!(claims.get("file_path").is_none());
+        assert!(claims.get("user_uuid").is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "MacError")]
+    fn rejects_other_secrets() {
+        let signer = UrlSigner::new("https://node.example.com:8080", "secret");
+        let url = signer.file_upload(SERVER);
+
+        verify(
+            &url,
+            "https://node.example.com:8080/upload/file?token=",
+            "other",
+        );
+    }
+}
@@ -0,0 +1,26 @@
+use pteroxide_models::wings::SystemInformation;
+
+use crate::{routing::Wings as Route, Builder, Error, Wings};
+
+#[derive(Debug)]
+pub struct GetSystemInformation<'a> {
+    wings: &'a Wings,
+}
+
+impl<'a> GetSystemInformation<'a> {
+    #[doc(hidden)]
+    pub const fn new(wings: &'a Wings) -> Self {
+        Self { wings }
+    }
+
+    /// Asynchronously executes the request and returns the [`SystemInformation`] of the node.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an [`Error`] if the request fails.
+    pub async fn exec(&self) -> Result<SystemInformation, Error> {
+        self.wings
+            .request::<SystemInformation>(Builder::new(Route::GetSystemInformation.into()))
+            .await
+    }
+}
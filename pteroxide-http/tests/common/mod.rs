@@ -0,0 +1,36 @@
+use hyper::{body::Bytes, Request, Response, StatusCode};
+use pteroxide_http::transport::{Transport, TransportFuture};
+use std::sync::{Arc, Mutex};
+
+#[derive(Debug, Default)]
+pub struct Exchanges {
+    pub requests: Mutex<Vec<Request<Bytes>>>,
+    pub responses: Mutex<Vec<(StatusCode, Vec<u8>)>>,
+}
+
+/// A transport that records the requests sent through it and answers each one with the next
+/// queued response.
+#[derive(Clone, Debug, Default)]
+pub struct MockTransport(pub Arc<Exchanges>);
+
+impl MockTransport {
+    pub fn respond(self, status: StatusCode, body: impl Into<Vec<u8>>) -> Self {
+        self.0.responses.lock().unwrap().push((status, body.into()));
+
+        self
+    }
+}
+
+impl Transport for MockTransport {
+    fn send(&self, request: Request<Bytes>) -> TransportFuture<'_> {
+        self.0.requests.lock().unwrap().push(request);
+        let (status, body) = self.0.responses.lock().unwrap().remove(0);
+
+        Box::pin(async move {
+            Ok(Response::builder()
+                .status(status)
+                .body(Bytes::from(body))
+                .unwrap())
+        })
+    }
+}
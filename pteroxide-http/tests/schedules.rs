@@ -0,0 +1,116 @@
+#![cfg(feature = "client")]
+
+use hyper::{Method, StatusCode};
+use pteroxide_http::{error::ErrorKind, Client};
+use pteroxide_models::{client::TaskAction, PowerSignal};
+use serde_json::{json, Value};
+
+use common::MockTransport;
+
+mod common;
+
+fn schedule() -> String {
+    json!({
+        "object": "server_schedule",
+        "attributes": {
+            "id": 1,
+            "name": "Restart",
+            "cron": {
+                "minute": "0",
+                "hour": "4",
+                "day_of_month": "*",
+                "month": "*",
+                "day_of_week": "*"
+            },
+            "is_active": true,
+            "is_processing": false,
+            "only_when_online": false,
+            "last_run_at": null,
+            "next_run_at": "2022-01-02T04:00:00+00:00",
+            "created_at": "2022-01-01T00:00:00+00:00",
+            "updated_at": "2022-01-01T00:00:00+00:00",
+            "relationships": {
+                "tasks": {
+                    "object": "list",
+                    "data": [task(30)]
+                }
+            }
+        }
+    })
+    .to_string()
+}
+
+fn task(time_offset: i32) -> Value {
+    json!({
+        "object": "schedule_task",
+        "attributes": {
+            "id": 2,
+            "sequence_id": 1,
+            "action": "power",
+            "payload": "restart",
+            "time_offset": time_offset,
+            "is_queued": false,
+            "continue_on_failure": true,
+            "created_at": "2022-01-01T00:00:00+00:00",
+            "updated_at": "2022-01-01T00:00:00+00:00"
+        }
+    })
+}
+
+#[tokio::test]
+async fn updates_tasks_with_existing_fields() {
+    let transport = MockTransport::default()
+        .respond(StatusCode::OK, schedule())
+        .respond(StatusCode::OK, task(60).to_string());
+    let exchanges = transport.0.clone();
+    let client = Client::with_transport(
+        "https://panel.example.com".to_string(),
+        "ptlc_key".to_string(),
+        transport,
+    );
+
+    let task = client
+        .update_task("1a7ce997", 1, 2)
+        .time_offset(60)
+        .exec()
+        .await
+        .unwrap();
+    assert_eq!(task.time_offset, 60);
+
+    let requests = exchanges.requests.lock().unwrap();
+    assert_eq!(requests[1].method(), Method::POST);
+    assert_eq!(
+        serde_json::from_slice::<Value>(requests[1].body()).unwrap(),
+        json!({
+            "action": "power",
+            "payload": "restart",
+            "time_offset": 60,
+            "continue_on_failure": true
+        })
+    );
+    assert_eq!(task.action, TaskAction::Power(PowerSignal::Restart));
+}
+
+#[tokio::test]
+async fn fails_to_update_missing_tasks() {
+    let transport = MockTransport::default().respond(StatusCode::OK, schedule());
+    let exchanges = transport.0.clone();
+    let client = Client::with_transport(
+        "https://panel.example.com".to_string(),
+        "ptlc_key".to_string(),
+        transport,
+    );
+
+    let err = client
+        .update_task("1a7ce997", 1, 3)
+        .exec()
+        .await
+        .unwrap_err();
+    match err.kind() {
+        ErrorKind::FractalError(e) => assert_eq!(e.errors[0].code, "NotFoundHttpException"),
+        kind => panic!("unexpected error kind: {:?}", kind),
+    }
+
+    // the task is not updated with default values
+    assert_eq!(exchanges.requests.lock().unwrap().len(), 1);
+}
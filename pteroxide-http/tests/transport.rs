@@ -0,0 +1,87 @@
+use hyper::{Method, StatusCode};
+use pteroxide_http::{error::ErrorKind, Application};
+use std::sync::Arc;
+
+use common::{Exchanges, MockTransport};
+
+mod common;
+
+fn app(transport: MockTransport) -> (Application, Arc<Exchanges>) {
+    let exchanges = transport.0.clone();
+    let app = Application::with_transport(
+        "https://panel.example.com".to_string(),
+        "ptla_key".to_string(),
+        transport,
+    );
+
+    (app, exchanges)
+}
+
+const USER: &str = r#"{
+    "object": "user",
+    "attributes": {
+        "id": 1,
+        "external_id": null,
+        "uuid": "c4022c6c-9bf1-4a23-bff9-519cceb38335",
+        "username": "admin",
+        "email": "admin@example.com",
+        "first_name": "Admin",
+        "last_name": "User",
+        "language": "en",
+        "root_admin": true,
+        "2fa": false,
+        "created_at": "2022-01-01T00:00:00+00:00",
+        "updated_at": "2022-01-01T00:00:00+00:00"
+    }
+}"#;
+
+#[tokio::test]
+async fn sends_requests_through_the_transport() {
+    let (app, transport) = app(MockTransport::default().respond(StatusCode::OK, USER));
+
+    let user = app.get_user(1).with_servers(true).exec().await.unwrap();
+    assert_eq!(user.username, "admin");
+    assert!(user.root_admin);
+
+    let requests = transport.requests.lock().unwrap();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].method(), Method::GET);
+    assert_eq!(
+        requests[0].uri(),
+        "https://panel.example.com/api/application/users/1?include=servers"
+    );
+    assert_eq!(requests[0].headers()["authorization"], "Bearer ptla_key");
+}
+
+#[tokio::test]
+async fn reports_undecodable_bodies_with_a_truncated_snippet() {
+    // the odd-length prefix puts byte 256 in the middle of a two-byte character
+    let page = format!("<html><body>!{}</body></html>", "é".repeat(300));
+    let (app, _) = app(MockTransport::default()
+        .respond(StatusCode::OK, page.clone())
+        .respond(StatusCode::BAD_GATEWAY, page.clone()));
+
+    let err = app.get_user(1).exec().await.unwrap_err();
+    assert!(matches!(err.kind(), ErrorKind::DeserializeError(_)));
+    let body = err.response_body().unwrap();
+    assert_eq!(body.status, StatusCode::OK);
+    assert_eq!(body.snippet.len(), 255);
+    assert!(page.starts_with(&body.snippet));
+
+    let err = app.get_user(1).exec().await.unwrap_err();
+    assert!(matches!(err.kind(), ErrorKind::ResponseError(_)));
+    let body = err.response_body().unwrap();
+    assert_eq!(body.status, StatusCode::BAD_GATEWAY);
+    assert_eq!(body.path, None);
+    assert!(page.starts_with(&body.snippet));
+}
+
+#[tokio::test]
+async fn reports_the_path_of_mismatched_fields() {
+    let body = USER.replace(r#""root_admin": true"#, r#""root_admin": "yes""#);
+    let (app, _) = app(MockTransport::default().respond(StatusCode::OK, body));
+
+    let err = app.get_user(1).exec().await.unwrap_err();
+    let body = err.response_body().unwrap();
+    assert_eq!(body.path.as_deref(), Some("attributes.root_admin"));
+}
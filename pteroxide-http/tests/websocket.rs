@@ -0,0 +1,143 @@
+#![cfg(feature = "websocket")]
+
+use futures_util::{SinkExt, StreamExt};
+use hyper::StatusCode;
+use pteroxide_http::{client::websocket::WebSocketEvent, error::ErrorKind, Client};
+use pteroxide_models::PowerState;
+use serde_json::{json, Value};
+use tokio::net::{TcpListener, TcpStream};
+use tokio_tungstenite::{
+    tungstenite::{
+        handshake::server::{ErrorResponse, Request, Response},
+        Message,
+    },
+    WebSocketStream,
+};
+
+use common::MockTransport;
+
+mod common;
+
+// the callback signature is set by tungstenite
+#[allow(clippy::result_large_err)]
+fn check_origin(req: &Request, res: Response) -> Result<Response, ErrorResponse> {
+    assert_eq!(req.headers()["Origin"], "https://panel.example.com");
+
+    Ok(res)
+}
+
+async fn recv(ws: &mut WebSocketStream<TcpStream>) -> Value {
+    loop {
+        if let Message::Text(data) = ws.next().await.unwrap().unwrap() {
+            return serde_json::from_str(&data).unwrap();
+        }
+    }
+}
+
+async fn send(ws: &mut WebSocketStream<TcpStream>, payload: Value) {
+    ws.send(Message::Text(payload.to_string())).await.unwrap();
+}
+
+#[tokio::test]
+async fn streams_events_and_refreshes_the_token() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let auth = |token: &str| {
+        json!({
+            "data": {
+                "token": token,
+                "socket": format!("ws://{}/api/servers/1a7ce997/ws", addr),
+            }
+        })
+        .to_string()
+    };
+
+    let transport = MockTransport::default()
+        .respond(StatusCode::OK, auth("first"))
+        .respond(StatusCode::OK, auth("second"));
+    let exchanges = transport.0.clone();
+    let client = Client::with_transport(
+        "https://panel.example.com".to_string(),
+        "ptlc_key".to_string(),
+        transport,
+    );
+
+    let node = tokio::spawn(async move {
+        let (tcp, _) = listener.accept().await.unwrap();
+        let mut ws = tokio_tungstenite::accept_hdr_async(tcp, check_origin)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            recv(&mut ws).await,
+            json!({"event": "auth", "args": ["first"]})
+        );
+        send(&mut ws, json!({"event": "auth success"})).await;
+        send(
+            &mut ws,
+            json!({"event": "console output", "args": ["hello"]}),
+        )
+        .await;
+        send(&mut ws, json!({"event": "status", "args": ["running"]})).await;
+        send(&mut ws, json!({"event": "stats", "args": ["{not json"]})).await;
+        send(&mut ws, json!({"event": "custom", "args": ["a", "b"]})).await;
+        send(&mut ws, json!({"event": "token expiring"})).await;
+
+        assert_eq!(
+            recv(&mut ws).await,
+            json!({"event": "auth", "args": ["second"]})
+        );
+        assert_eq!(
+            recv(&mut ws).await,
+            json!({"event": "send command", "args": ["say hi"]})
+        );
+
+        ws.close(None).await.unwrap();
+        while ws.next().await.is_some() {}
+    });
+
+    let (sender, events) = client.connect_websocket("1a7ce997").exec().await.unwrap();
+    futures_util::pin_mut!(events);
+
+    assert_eq!(
+        events.next().await.unwrap().unwrap(),
+        WebSocketEvent::AuthSuccess
+    );
+    assert_eq!(
+        events.next().await.unwrap().unwrap(),
+        WebSocketEvent::ConsoleOutput("hello".to_string())
+    );
+    assert_eq!(
+        events.next().await.unwrap().unwrap(),
+        WebSocketEvent::Status(PowerState::Running)
+    );
+
+    // a malformed event is yielded as an error without ending the stream
+    let err = events.next().await.unwrap().unwrap_err();
+    assert!(matches!(err.kind(), ErrorKind::DeserializeError(_)));
+
+    assert_eq!(
+        events.next().await.unwrap().unwrap(),
+        WebSocketEvent::Unknown {
+            event: "custom".to_string(),
+            args: vec!["a".to_string(), "b".to_string()],
+        }
+    );
+    assert_eq!(
+        events.next().await.unwrap().unwrap(),
+        WebSocketEvent::TokenExpiring
+    );
+
+    sender.send_command("say hi").await.unwrap();
+    assert!(events.next().await.is_none());
+    node.await.unwrap();
+
+    let requests = exchanges.requests.lock().unwrap();
+    assert_eq!(requests.len(), 2);
+    for req in requests.iter() {
+        assert_eq!(
+            req.uri(),
+            "https://panel.example.com/api/client/servers/1a7ce997/websocket"
+        );
+    }
+}
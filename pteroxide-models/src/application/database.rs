@@ -0,0 +1,38 @@
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "app-relations")]
+use super::relations::DatabaseRelations;
+
+/// Represents a database object for a server.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Database {
+    pub id: i32,
+    pub server: i32,
+    pub host: i32,
+    pub database: String,
+    pub username: String,
+    pub remote: String,
+    pub max_connections: Option<i32>,
+    pub created_at: String,
+    pub updated_at: Option<String>,
+    #[cfg(feature = "app-relations")]
+    #[serde(default)]
+    #[serde(skip_serializing)]
+    pub relationships: Option<DatabaseRelations>,
+}
+
+#[cfg(feature = "time")]
+crate::impl_time!(Database);
+
+/// Represents a database host object, the database server that server databases are created on.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct DatabaseHost {
+    pub id: i32,
+    pub name: String,
+    pub host: String,
+    pub port: i32,
+    pub username: String,
+    pub node: Option<i32>,
+    pub created_at: String,
+    pub updated_at: Option<String>,
+}
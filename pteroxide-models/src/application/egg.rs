@@ -6,7 +6,7 @@ use super::EggRelations;
 
 /// Represents an egg (service) object, containing all the necessary information about the service
 /// including Docker images, startup scripts, and parser configurations.
-#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct Egg {
     pub id: i32,
     pub uuid: String,
@@ -57,3 +57,28 @@ pub struct EggScript {
     pub container: String,
     pub extends: Option<String>,
 }
+
+/// Represents a variable of an [`Egg`], which is set as an environment variable in the server
+/// container.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct EggVariable {
+    pub id: i32,
+    pub egg_id: i32,
+    pub name: String,
+    pub description: String,
+    pub env_variable: String,
+    pub default_value: Option<String>,
+    /// The value of the variable for a server. This is only set when the variable is fetched as
+    /// part of the server [`relationships`].
+    ///
+    /// [`relationships`]: super::ServerRelations
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub server_value: Option<String>,
+    pub user_viewable: bool,
+    pub user_editable: bool,
+    /// The Laravel validation rules for the variable value, such as `required|string|max:20`.
+    pub rules: String,
+    pub created_at: String,
+    pub updated_at: Option<String>,
+}
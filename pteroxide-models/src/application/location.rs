@@ -7,7 +7,7 @@ use super::LocationRelations;
 /// management and server deployment.
 ///
 /// [`Node`]: super::node::Node
-#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct Location {
     pub id: i32,
     pub short: String,
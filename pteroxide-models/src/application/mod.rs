@@ -1,4 +1,5 @@
 pub mod allocation;
+pub mod database;
 pub mod egg;
 pub mod location;
 pub mod nest;
@@ -11,5 +12,6 @@ pub mod users;
 #[cfg(feature = "app-relations")]
 pub use self::relations::*;
 pub use self::{
-    allocation::Allocation, egg::*, location::Location, nest::Nest, node::*, server::*, users::*,
+    allocation::Allocation, database::*, egg::*, location::Location, nest::Nest, node::*,
+    server::*, users::*,
 };
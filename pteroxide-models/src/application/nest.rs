@@ -4,7 +4,7 @@ use serde::{Deserialize, Serialize};
 use super::NestRelations;
 
 /// Represents a nest object containing eggs (services) information.
-#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct Nest {
     pub id: i32,
     pub uuid: String,
@@ -15,7 +15,7 @@ pub struct APIConfiguration {
 /// Represents a node object. This contains general information about the node such as the
 /// location ID, Fully Qualified Domain Name (FQDN), memory/disk resources and allocated resources.
 /// Most fields of a [`NodeConfiguration`] object can be derived from this object.
-#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct Node {
     pub id: i32,
     pub name: String,
@@ -0,0 +1,60 @@
+use serde::{
+    de::{value::MapAccessDeserializer, MapAccess, Visitor},
+    Deserialize,
+};
+use std::fmt::{Formatter, Result as FmtResult};
+
+use crate::{application::DatabaseHost, fractal::FractalItem};
+
+#[derive(Deserialize)]
+#[doc(hidden)]
+struct DatabasePassword {
+    password: String,
+}
+
+#[derive(Deserialize)]
+#[doc(hidden)]
+struct RawDatabaseRelations {
+    password: Option<FractalItem<DatabasePassword>>,
+    host: Option<FractalItem<DatabaseHost>>,
+}
+
+#[doc(hidden)]
+struct RelationsVisitor;
+
+impl<'de> Visitor<'de> for RelationsVisitor {
+    type Value = DatabaseRelations;
+
+    fn expecting(&self, formatter: &mut Formatter) -> FmtResult {
+        formatter.write_str("a map of database relationships")
+    }
+
+    fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let des = MapAccessDeserializer::new(map);
+        let rel = RawDatabaseRelations::deserialize(des)?;
+
+        Ok(DatabaseRelations {
+            password: rel.password.map(|p| p.attributes.password),
+            host: rel.host.map(|h| h.attributes),
+        })
+    }
+}
+
+/// Represents the relationship objects for a database.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DatabaseRelations {
+    pub password: Option<String>,
+    pub host: Option<DatabaseHost>,
+}
+
+impl<'de> Deserialize<'de> for DatabaseRelations {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_map(RelationsVisitor)
+    }
+}
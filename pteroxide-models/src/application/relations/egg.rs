@@ -5,7 +5,7 @@ use serde::{
 use std::fmt::{Formatter, Result as FmtResult};
 
 use crate::{
-    application::{EggConfig, EggScript, Nest, Server},
+    application::{EggConfig, EggScript, EggVariable, Nest, Server},
     fractal::{FractalItem, FractalList},
 };
 
@@ -16,7 +16,7 @@ struct RawEggRelations {
     nest: Option<FractalItem<Nest>>,
     script: Option<FractalItem<EggScript>>,
     servers: Option<FractalList<Server>>,
-    // variables
+    variables: Option<FractalList<EggVariable>>,
 }
 
 #[doc(hidden)]
@@ -53,16 +53,21 @@ impl<'de> Visitor<'de> for RelationsVisitor {
                 Some(v) => Some(v.data.iter().map(|s| s.attributes.clone()).collect()),
                 None => None,
             },
+            variables: match rel.variables {
+                Some(v) => Some(v.data.iter().map(|v| v.attributes.clone()).collect()),
+                None => None,
+            },
         })
     }
 }
 
-#[derive(Clone, Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct EggRelations {
     pub config: Option<EggConfig>,
     pub nest: Option<Nest>,
     pub script: Option<EggScript>,
     pub servers: Option<Vec<Server>>,
+    pub variables: Option<Vec<EggVariable>>,
 }
 
 impl<'de> Deserialize<'de> for EggRelations {
@@ -46,7 +46,7 @@ impl<'de> Visitor<'de> for RelationsVisitor {
     }
 }
 
-#[derive(Clone, Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct LocationRelations {
     pub nodes: Option<Vec<Node>>,
     pub servers: Option<Vec<Server>>,
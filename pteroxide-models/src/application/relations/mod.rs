@@ -1,3 +1,4 @@
+pub mod database;
 pub mod egg;
 pub mod location;
 pub mod nest;
@@ -6,6 +7,6 @@ pub mod server;
 pub mod user;
 
 pub use self::{
-    egg::EggRelations, location::LocationRelations, nest::NestRelations, node::NodeRelations,
-    server::ServerRelations, user::UserRelations,
+    database::DatabaseRelations, egg::EggRelations, location::LocationRelations,
+    nest::NestRelations, node::NodeRelations, server::ServerRelations, user::UserRelations,
 };
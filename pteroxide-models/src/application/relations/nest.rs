@@ -46,7 +46,7 @@ impl<'de> Visitor<'de> for RelationsVisitor {
     }
 }
 
-#[derive(Clone, Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct NestRelations {
     pub eggs: Option<Vec<Egg>>,
     pub servers: Option<Vec<Server>>,
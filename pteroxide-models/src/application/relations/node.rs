@@ -51,7 +51,7 @@ impl<'de> Visitor<'de> for RelationsVisitor {
     }
 }
 
-#[derive(Clone, Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct NodeRelations {
     pub allocations: Option<Vec<Allocation>>,
     pub location: Option<Location>,
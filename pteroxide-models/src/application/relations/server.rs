@@ -5,7 +5,7 @@ use serde::{
 use std::fmt::{Formatter, Result as FmtResult};
 
 use crate::{
-    application::{Allocation, Egg, Location, Nest, Node, SubUser, User},
+    application::{Allocation, Database, Egg, EggVariable, Location, Nest, Node, SubUser, User},
     fractal::{FractalItem, FractalList},
 };
 
@@ -17,8 +17,10 @@ struct RawServerRelations {
     subusers: Option<FractalList<SubUser>>,
     nest: Option<FractalItem<Nest>>,
     egg: Option<FractalItem<Egg>>,
+    variables: Option<FractalList<EggVariable>>,
     location: Option<FractalItem<Location>>,
     node: Option<FractalItem<Node>>,
+    databases: Option<FractalList<Database>>,
 }
 
 #[doc(hidden)]
@@ -59,6 +61,10 @@ impl<'de> Visitor<'de> for RelationsVisitor {
                 Some(e) => Some(e.attributes),
                 None => None,
             },
+            variables: match rel.variables {
+                Some(v) => Some(v.data.iter().map(|v| v.attributes.clone()).collect()),
+                None => None,
+            },
             location: match rel.location {
                 Some(l) => Some(l.attributes),
                 None => None,
@@ -67,20 +73,26 @@ impl<'de> Visitor<'de> for RelationsVisitor {
                 Some(n) => Some(n.attributes),
                 None => None,
             },
+            databases: match rel.databases {
+                Some(v) => Some(v.data.iter().map(|d| d.attributes.clone()).collect()),
+                None => None,
+            },
         })
     }
 }
 
 /// Represents the relationship objects for a server.
-#[derive(Clone, Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct ServerRelations {
     pub allocations: Option<Vec<Allocation>>,
     pub user: Option<User>,
     pub subusers: Option<Vec<SubUser>>,
     pub nest: Option<Nest>,
     pub egg: Option<Egg>,
+    pub variables: Option<Vec<EggVariable>>,
     pub location: Option<Location>,
     pub node: Option<Node>,
+    pub databases: Option<Vec<Database>>,
 }
 
 impl<'de> Deserialize<'de> for ServerRelations {
@@ -39,7 +39,7 @@ impl<'de> Visitor<'de> for RelationsVisitor {
 }
 
 /// Represents the relationship objects for a user.
-#[derive(Clone, Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct UserRelations {
     pub servers: Option<Vec<Server>>,
 }
@@ -1,25 +1,26 @@
 use serde::{Deserialize, Serialize};
-// use std::collections::HashMap;
+use std::collections::HashMap;
 
 #[cfg(feature = "app-relations")]
 use super::relations::ServerRelations;
-use crate::{FeatureLimits, Limits /* Value */};
+use crate::{FeatureLimits, Limits, Value};
 
 /// Represents the container details for the server, such as the startup command and docker image.
-#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct Container {
     pub startup_command: String,
     pub image: String,
     pub installed: i8,
-    // TODO
-    // pub environment: HashMap<String, Value>,
+    /// The environment variables of the server container, including the values of the egg
+    /// variables and the `P_SERVER_*` variables set by the panel.
+    pub environment: HashMap<String, Value>,
 }
 
 /// Represents a server object. This contains general information about the server such as the
 /// UUID, (feature) limits, and the status in the panel. Note that this is NOT a representation of
 /// the server/container on Wings, so it does not contain information like the current power state
 /// or resource usage.
-#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct Server {
     pub id: i32,
     pub external_id: Option<String>,
@@ -78,6 +79,7 @@ impl From<String> for Status {
     }
 }
 
+#[allow(clippy::to_string_trait_impl)]
 impl ToString for Status {
     fn to_string(&self) -> String {
         match self {
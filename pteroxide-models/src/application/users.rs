@@ -2,9 +2,10 @@ use serde::{Deserialize, Serialize};
 
 #[cfg(feature = "app-relations")]
 use super::relations::UserRelations;
+use crate::Permission;
 
 /// Represents a user object.
-#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct User {
     pub id: i32,
     pub external_id: Option<String>,
@@ -34,7 +35,7 @@ pub struct SubUser {
     pub id: i32,
     pub user_id: i32,
     pub server_id: i32,
-    pub permissions: Vec<String>,
+    pub permissions: Vec<Permission>,
     pub created_at: String,
     pub updated_at: Option<String>,
 }
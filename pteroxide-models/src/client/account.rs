@@ -0,0 +1,33 @@
+use serde::{Deserialize, Serialize};
+
+/// Represents the account of the user the client API key belongs to.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Account {
+    pub id: i32,
+    pub admin: bool,
+    pub username: String,
+    pub email: String,
+    pub first_name: String,
+    pub last_name: String,
+    pub language: String,
+}
+
+/// Represents a client API key object. The secret token of the key is only returned when it is
+/// created.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct ApiKey {
+    pub identifier: String,
+    pub description: String,
+    pub allowed_ips: Vec<String>,
+    pub last_used_at: Option<String>,
+    pub created_at: String,
+}
+
+/// Represents the two-factor authentication setup data of an account, used to generate the codes
+/// for enabling two-factor authentication.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct TwoFactorData {
+    pub image_url_data: String,
+    #[serde(default)]
+    pub secret: Option<String>,
+}
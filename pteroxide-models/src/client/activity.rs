@@ -0,0 +1,42 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+#[cfg(feature = "client-relations")]
+use super::relations::ActivityLogRelations;
+
+/// Represents an activity log object of an account or server.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct ActivityLog {
+    pub id: String,
+    /// The UUID of the batch the activity was logged in, shared by activities caused by the same
+    /// action.
+    pub batch: Option<String>,
+    /// The name of the event, such as `auth:success` or `server:file.write`.
+    pub event: String,
+    pub is_api: bool,
+    /// The IP address the activity came from. This is only visible to the actor themselves and
+    /// to admins.
+    pub ip: Option<String>,
+    pub description: Option<String>,
+    /// The event-specific properties of the activity, such as the files that were changed.
+    pub properties: HashMap<String, Value>,
+    pub has_additional_metadata: bool,
+    pub timestamp: String,
+    #[cfg(feature = "client-relations")]
+    #[serde(default)]
+    #[serde(skip_serializing)]
+    pub relationships: Option<ActivityLogRelations>,
+}
+
+/// Represents the user that performed an activity.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct ActivityActor {
+    pub uuid: String,
+    pub username: String,
+    pub email: String,
+    pub image: String,
+    #[serde(rename = "2fa_enabled")]
+    pub two_factor: bool,
+    pub created_at: String,
+}
@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+/// Represents a network allocation object of a server.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Allocation {
+    pub id: i32,
+    pub ip: String,
+    pub ip_alias: Option<String>,
+    pub port: i32,
+    pub notes: Option<String>,
+    /// Whether this is the primary allocation of the server.
+    pub is_default: bool,
+}
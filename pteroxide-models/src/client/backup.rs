@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+
+/// Represents a server backup object.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Backup {
+    pub uuid: String,
+    pub is_successful: bool,
+    pub is_locked: bool,
+    pub name: String,
+    pub ignored_files: Vec<String>,
+    pub checksum: Option<String>,
+    pub bytes: i64,
+    pub created_at: String,
+    pub completed_at: Option<String>,
+}
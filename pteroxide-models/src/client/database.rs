@@ -0,0 +1,26 @@
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "client-relations")]
+use super::relations::ServerDatabaseRelations;
+
+/// Represents the address of the database host a server database is on.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct DatabaseHost {
+    pub address: String,
+    pub port: i32,
+}
+
+/// Represents a server database object.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct ServerDatabase {
+    pub id: String,
+    pub host: DatabaseHost,
+    pub name: String,
+    pub username: String,
+    pub connections_from: String,
+    pub max_connections: i32,
+    #[cfg(feature = "client-relations")]
+    #[serde(default)]
+    #[serde(skip_serializing)]
+    pub relationships: Option<ServerDatabaseRelations>,
+}
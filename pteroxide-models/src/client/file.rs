@@ -0,0 +1,23 @@
+use serde::{Deserialize, Serialize};
+
+/// Represents a file or directory on a server.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct FileObject {
+    pub name: String,
+    /// The file mode in its symbolic form, such as `-rw-r--r--`.
+    pub mode: String,
+    /// The file permission bits in octal, such as `644`.
+    pub mode_bits: String,
+    pub size: i64,
+    pub is_file: bool,
+    pub is_symlink: bool,
+    pub mimetype: String,
+    pub created_at: String,
+    pub modified_at: String,
+}
+
+/// Represents a signed URL for downloading or uploading files on the node directly.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct SignedUrl {
+    pub url: String,
+}
@@ -0,0 +1,20 @@
+pub mod account;
+pub mod activity;
+pub mod allocation;
+pub mod backup;
+pub mod database;
+pub mod file;
+#[cfg(feature = "client-relations")]
+pub mod relations;
+pub mod schedule;
+pub mod server;
+pub mod startup;
+pub mod subuser;
+pub mod websocket;
+
+#[cfg(feature = "client-relations")]
+pub use self::relations::*;
+pub use self::{
+    account::*, activity::*, allocation::*, backup::*, database::*, file::*, schedule::*,
+    server::*, startup::*, subuser::*, websocket::*,
+};
@@ -0,0 +1,100 @@
+use serde::{
+    de::{value::MapAccessDeserializer, MapAccess, Visitor},
+    Deserialize,
+};
+use std::fmt::{Formatter, Result as FmtResult};
+
+use crate::{client::ActivityActor, fractal::FractalItem};
+
+#[derive(Deserialize)]
+#[doc(hidden)]
+struct RawActivityLogRelations {
+    // system and API key activity has a `null_resource` actor with null attributes
+    actor: Option<FractalItem<Option<ActivityActor>>>,
+}
+
+#[doc(hidden)]
+struct RelationsVisitor;
+
+impl<'de> Visitor<'de> for RelationsVisitor {
+    type Value = ActivityLogRelations;
+
+    fn expecting(&self, formatter: &mut Formatter) -> FmtResult {
+        formatter.write_str("a map of activity log relationships")
+    }
+
+    fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let des = MapAccessDeserializer::new(map);
+        let rel = RawActivityLogRelations::deserialize(des)?;
+
+        Ok(ActivityLogRelations {
+            actor: rel.actor.and_then(|a| a.attributes),
+        })
+    }
+}
+
+/// Represents the relationship objects for an activity log. The actor is [`None`] if the
+/// activity was performed by the system or an API key rather than a user.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ActivityLogRelations {
+    pub actor: Option<ActivityActor>,
+}
+
+impl<'de> Deserialize<'de> for ActivityLogRelations {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_map(RelationsVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::ActivityLogRelations;
+
+    #[test]
+    fn deserializes_user_actor() {
+        let rel: ActivityLogRelations = serde_json::from_value(json!({
+            "actor": {
+                "object": "user",
+                "attributes": {
+                    "uuid": "5d1f6a3e-8d2c-4b6a-9f1e-2c3b4a5d6e7f",
+                    "username": "admin",
+                    "email": "admin@example.com",
+                    "image": "https://gravatar.com/avatar/0",
+                    "2fa_enabled": false,
+                    "created_at": "2022-10-01T12:00:00+00:00"
+                }
+            }
+        }))
+        .unwrap();
+
+        assert_eq!(rel.actor.unwrap().username, "admin");
+    }
+
+    #[test]
+    fn deserializes_null_resource_actor() {
+        let rel: ActivityLogRelations = serde_json::from_value(json!({
+            "actor": {
+                "object": "null_resource",
+                "attributes": null
+            }
+        }))
+        .unwrap();
+
+        assert_eq!(rel.actor, None);
+    }
+
+    #[test]
+    fn deserializes_missing_actor() {
+        let rel: ActivityLogRelations = serde_json::from_value(json!({})).unwrap();
+
+        assert_eq!(rel.actor, None);
+    }
+}
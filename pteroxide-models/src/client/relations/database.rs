@@ -0,0 +1,57 @@
+use serde::{
+    de::{value::MapAccessDeserializer, MapAccess, Visitor},
+    Deserialize,
+};
+use std::fmt::{Formatter, Result as FmtResult};
+
+use crate::fractal::FractalItem;
+
+#[derive(Deserialize)]
+#[doc(hidden)]
+struct DatabasePassword {
+    password: String,
+}
+
+#[derive(Deserialize)]
+#[doc(hidden)]
+struct RawServerDatabaseRelations {
+    password: Option<FractalItem<DatabasePassword>>,
+}
+
+#[doc(hidden)]
+struct RelationsVisitor;
+
+impl<'de> Visitor<'de> for RelationsVisitor {
+    type Value = ServerDatabaseRelations;
+
+    fn expecting(&self, formatter: &mut Formatter) -> FmtResult {
+        formatter.write_str("a map of server database relationships")
+    }
+
+    fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let des = MapAccessDeserializer::new(map);
+        let rel = RawServerDatabaseRelations::deserialize(des)?;
+
+        Ok(ServerDatabaseRelations {
+            password: rel.password.map(|p| p.attributes.password),
+        })
+    }
+}
+
+/// Represents the relationship objects for a server database.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ServerDatabaseRelations {
+    pub password: Option<String>,
+}
+
+impl<'de> Deserialize<'de> for ServerDatabaseRelations {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_map(RelationsVisitor)
+    }
+}
@@ -0,0 +1,7 @@
+pub mod activity;
+pub mod database;
+pub mod schedule;
+
+pub use self::{
+    activity::ActivityLogRelations, database::ServerDatabaseRelations, schedule::ScheduleRelations,
+};
@@ -0,0 +1,54 @@
+use serde::{
+    de::{value::MapAccessDeserializer, MapAccess, Visitor},
+    Deserialize,
+};
+use std::fmt::{Formatter, Result as FmtResult};
+
+use crate::{client::Task, fractal::FractalList};
+
+#[derive(Deserialize)]
+#[doc(hidden)]
+struct RawScheduleRelations {
+    tasks: Option<FractalList<Task>>,
+}
+
+#[doc(hidden)]
+struct RelationsVisitor;
+
+impl<'de> Visitor<'de> for RelationsVisitor {
+    type Value = ScheduleRelations;
+
+    fn expecting(&self, formatter: &mut Formatter) -> FmtResult {
+        formatter.write_str("a map of schedule relationships")
+    }
+
+    fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let des = MapAccessDeserializer::new(map);
+        let rel = RawScheduleRelations::deserialize(des)?;
+
+        Ok(ScheduleRelations {
+            tasks: match rel.tasks {
+                Some(v) => Some(v.data.iter().map(|t| t.attributes.clone()).collect()),
+                None => None,
+            },
+        })
+    }
+}
+
+/// Represents the relationship objects for a schedule.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ScheduleRelations {
+    pub tasks: Option<Vec<Task>>,
+}
+
+impl<'de> Deserialize<'de> for ScheduleRelations {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_map(RelationsVisitor)
+    }
+}
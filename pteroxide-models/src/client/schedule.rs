@@ -0,0 +1,95 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+#[cfg(feature = "client-relations")]
+use super::relations::ScheduleRelations;
+use crate::PowerSignal;
+
+/// Represents the cron expression of a schedule. Each field uses the standard cron syntax, such as
+/// `*`, `*/5` or `1-5`.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Cron {
+    pub minute: String,
+    pub hour: String,
+    pub day_of_month: String,
+    pub month: String,
+    pub day_of_week: String,
+}
+
+impl Cron {
+    /// Creates a new cron expression from the individual fields.
+    pub fn new(
+        minute: &str,
+        hour: &str,
+        day_of_month: &str,
+        month: &str,
+        day_of_week: &str,
+    ) -> Self {
+        Self {
+            minute: minute.to_string(),
+            hour: hour.to_string(),
+            day_of_month: day_of_month.to_string(),
+            month: month.to_string(),
+            day_of_week: day_of_week.to_string(),
+        }
+    }
+}
+
+impl Display for Cron {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(
+            f,
+            "{} {} {} {} {}",
+            self.minute, self.hour, self.day_of_month, self.month, self.day_of_week
+        )
+    }
+}
+
+/// Represents a server schedule object.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Schedule {
+    pub id: i32,
+    pub name: String,
+    pub cron: Cron,
+    pub is_active: bool,
+    pub is_processing: bool,
+    pub only_when_online: bool,
+    pub last_run_at: Option<String>,
+    pub next_run_at: Option<String>,
+    pub created_at: String,
+    pub updated_at: Option<String>,
+    #[cfg(feature = "client-relations")]
+    #[serde(default)]
+    #[serde(skip_serializing)]
+    pub relationships: Option<ScheduleRelations>,
+}
+
+#[cfg(feature = "time")]
+crate::impl_time!(Schedule);
+
+/// Represents the action performed by a schedule task, along with its payload.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(tag = "action", content = "payload", rename_all = "lowercase")]
+pub enum TaskAction {
+    /// Sends a command to the server console.
+    Command(String),
+    /// Sends a power signal to the server.
+    Power(PowerSignal),
+    /// Creates a backup of the server, ignoring the files in the payload (newline-separated).
+    Backup(Option<String>),
+}
+
+/// Represents a schedule task object.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Task {
+    pub id: i32,
+    pub sequence_id: i32,
+    #[serde(flatten)]
+    pub action: TaskAction,
+    /// The time to wait after the previous task before running this task, in seconds.
+    pub time_offset: i32,
+    pub is_queued: bool,
+    pub continue_on_failure: bool,
+    pub created_at: String,
+    pub updated_at: Option<String>,
+}
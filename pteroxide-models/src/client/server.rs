@@ -0,0 +1,56 @@
+use serde::{Deserialize, Serialize};
+
+pub use crate::PowerState;
+use crate::{FeatureLimits, Limits};
+
+/// Represents the SFTP connection details for a server.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct SftpDetails {
+    pub ip: String,
+    pub port: i32,
+}
+
+/// Represents a server object from the client API. Unlike the application API server, this
+/// contains details relevant to the user accessing it, such as the SFTP details and whether the
+/// user owns the server.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Server {
+    pub server_owner: bool,
+    pub identifier: String,
+    pub internal_id: i32,
+    pub uuid: String,
+    pub name: String,
+    pub node: String,
+    pub is_node_under_maintenance: bool,
+    pub sftp_details: SftpDetails,
+    pub description: String,
+    pub limits: Limits,
+    pub invocation: String,
+    pub docker_image: String,
+    pub egg_features: Option<Vec<String>>,
+    pub feature_limits: FeatureLimits,
+    pub status: Option<String>,
+    pub is_suspended: bool,
+    pub is_installing: bool,
+    pub is_transferring: bool,
+}
+
+/// Represents the resource usage of a server at the time of the request.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct ResourceUsage {
+    pub memory_bytes: i64,
+    pub cpu_absolute: f64,
+    pub disk_bytes: i64,
+    pub network_rx_bytes: i64,
+    pub network_tx_bytes: i64,
+    /// The time the server has been running for, in milliseconds.
+    pub uptime: i64,
+}
+
+/// Represents the current state and resource utilization of a server.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Resources {
+    pub current_state: PowerState,
+    pub is_suspended: bool,
+    pub resources: ResourceUsage,
+}
@@ -0,0 +1,27 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Represents a startup variable of a server, based on the variables of its egg.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct StartupVariable {
+    pub name: String,
+    pub description: String,
+    pub env_variable: String,
+    pub default_value: String,
+    pub server_value: Option<String>,
+    pub is_editable: bool,
+    /// The Laravel validation rules for the variable value, such as `required|string|max:20`.
+    pub rules: String,
+}
+
+/// Represents the startup configuration of a server.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Startup {
+    /// The startup command with the variables replaced by their values.
+    pub startup_command: String,
+    /// The startup command as it is set on the server, without any variables replaced.
+    pub raw_startup_command: String,
+    /// A map of the docker image names to the images available for the server.
+    pub docker_images: HashMap<String, String>,
+    pub variables: Vec<StartupVariable>,
+}
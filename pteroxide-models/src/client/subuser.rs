@@ -0,0 +1,27 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::Permission;
+
+/// Represents a server subuser object.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct SubUser {
+    pub uuid: String,
+    pub username: String,
+    pub email: String,
+    pub image: String,
+    #[serde(rename = "2fa_enabled")]
+    pub two_factor: bool,
+    pub created_at: String,
+    pub permissions: Vec<Permission>,
+}
+
+/// Represents a group of permissions in the panel's permission catalogue, such as `control` or
+/// `file`.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct PermissionGroup {
+    pub description: String,
+    /// A map of the permission actions in the group to their descriptions. The full permission
+    /// key is the group name and action joined by a dot.
+    pub keys: HashMap<String, String>,
+}
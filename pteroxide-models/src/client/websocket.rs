@@ -0,0 +1,40 @@
+use serde::{Deserialize, Serialize};
+
+use super::PowerState;
+
+/// Represents the credentials for connecting to the websocket of a server on its node.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct WebSocketAuth {
+    pub token: String,
+    pub socket: String,
+}
+
+/// Represents the network usage of a server sent through the websocket.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct NetworkStats {
+    pub rx_bytes: i64,
+    pub tx_bytes: i64,
+}
+
+/// Represents the resource usage of a server sent through the websocket.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct WebSocketStats {
+    pub memory_bytes: i64,
+    pub memory_limit_bytes: i64,
+    pub cpu_absolute: f64,
+    pub network: NetworkStats,
+    pub state: PowerState,
+    /// The time the server has been running for, in milliseconds.
+    pub uptime: i64,
+    pub disk_bytes: i64,
+}
+
+/// Represents the result of a backup sent through the websocket once it has completed.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct BackupCompleted {
+    pub uuid: String,
+    pub is_successful: bool,
+    pub checksum: String,
+    pub checksum_type: String,
+    pub file_size: i64,
+}
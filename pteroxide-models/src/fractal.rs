@@ -50,19 +50,30 @@ pub struct FractalItem<T> {
     pub attributes: T,
 }
 
-/// Represents a Fractal list object which contains a list of [`FractalItem`] objects.
+/// Represents a Fractal list object which contains a list of [`FractalItem`] objects. Lists
+/// returned from list endpoints also contain the [`FractalMeta`] of the request, whereas lists
+/// included in relationships do not.
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub struct FractalList<T> {
     pub object: String,
     pub data: Vec<FractalItem<T>>,
-    // pub meta: FractalMeta,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub meta: Option<FractalMeta>,
 }
 
-// #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
-// pub struct FractalMeta {
-//     pub count: i32,
-//     pub total: i32,
-//     pub current_page: i32,
-//     pub per_page: i32,
-//     pub total_pages: i32,
-// }
+/// Represents the metadata of a Fractal list object.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct FractalMeta {
+    pub pagination: FractalPagination,
+}
+
+/// Represents the pagination information of a Fractal list object.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct FractalPagination {
+    pub total: i32,
+    pub count: i32,
+    pub per_page: i32,
+    pub current_page: i32,
+    pub total_pages: i32,
+}
@@ -2,12 +2,18 @@ use serde::{Deserialize, Serialize};
 
 #[cfg(feature = "app")]
 pub mod application;
+#[cfg(feature = "client")]
+pub mod client;
 #[cfg(feature = "fractal")]
 pub mod fractal;
+pub mod permission;
 #[cfg(feature = "time")]
 pub mod util;
 pub mod value;
+#[cfg(feature = "wings")]
+pub mod wings;
 
+pub use permission::Permission;
 pub use value::Value;
 
 /// Represents the feature limits of a server.
@@ -31,3 +37,35 @@ pub struct Limits {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub oom_disabled: Option<bool>,
 }
+
+/// Represents the power state of a server.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PowerState {
+    Offline,
+    Starting,
+    Running,
+    Stopping,
+}
+
+/// Represents a power signal that can be sent to a server.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PowerSignal {
+    Start,
+    Stop,
+    Restart,
+    Kill,
+}
+
+impl PowerSignal {
+    /// Returns the name of the power signal.
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            PowerSignal::Start => "start",
+            PowerSignal::Stop => "stop",
+            PowerSignal::Restart => "restart",
+            PowerSignal::Kill => "kill",
+        }
+    }
+}
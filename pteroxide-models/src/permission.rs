@@ -0,0 +1,185 @@
+use serde::{Deserialize, Serialize};
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+    str::FromStr,
+};
+
+macro_rules! permissions {
+    ($($(#[$meta:meta])* $name:ident => $key:literal,)*) => {
+        /// Represents a permission that can be given to a server subuser. Permissions are sent to
+        /// and received from the panel as strings in the `group.action` format, such as
+        /// `control.console` or `file.read`.
+        ///
+        /// Parsing a permission with [`FromStr`] fails for unknown keys so that typos are caught,
+        /// while deserializing falls back to [`Unknown`][Permission::Unknown] so that permissions
+        /// added by newer panel versions or addons do not break the model.
+        #[derive(Clone, Debug, Eq, Hash, PartialEq)]
+        pub enum Permission {
+            $($(#[$meta])* $name,)*
+            /// A permission that is not known to the library.
+            Unknown(String),
+        }
+
+        impl Permission {
+            /// A list of all the permissions known to the library.
+            pub const ALL: &'static [Permission] = &[$(Permission::$name,)*];
+
+            /// Returns the key of the permission used by the panel.
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $(Permission::$name => $key,)*
+                    Permission::Unknown(v) => v,
+                }
+            }
+        }
+
+        impl FromStr for Permission {
+            type Err = ParsePermissionError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                match s {
+                    $($key => Ok(Permission::$name),)*
+                    _ => Err(ParsePermissionError(s.to_string())),
+                }
+            }
+        }
+    };
+}
+
+permissions! {
+    /// Allows connecting to the server websocket. The panel gives this to every subuser.
+    WebsocketConnect => "websocket.connect",
+    ControlConsole => "control.console",
+    ControlStart => "control.start",
+    ControlStop => "control.stop",
+    ControlRestart => "control.restart",
+    UserCreate => "user.create",
+    UserRead => "user.read",
+    UserUpdate => "user.update",
+    UserDelete => "user.delete",
+    FileCreate => "file.create",
+    FileRead => "file.read",
+    FileReadContent => "file.read-content",
+    FileUpdate => "file.update",
+    FileDelete => "file.delete",
+    FileArchive => "file.archive",
+    FileSftp => "file.sftp",
+    BackupCreate => "backup.create",
+    BackupRead => "backup.read",
+    BackupDelete => "backup.delete",
+    BackupDownload => "backup.download",
+    BackupRestore => "backup.restore",
+    AllocationRead => "allocation.read",
+    AllocationCreate => "allocation.create",
+    AllocationUpdate => "allocation.update",
+    AllocationDelete => "allocation.delete",
+    StartupRead => "startup.read",
+    StartupUpdate => "startup.update",
+    StartupDockerImage => "startup.docker-image",
+    DatabaseCreate => "database.create",
+    DatabaseRead => "database.read",
+    DatabaseUpdate => "database.update",
+    DatabaseDelete => "database.delete",
+    DatabaseViewPassword => "database.view_password",
+    ScheduleCreate => "schedule.create",
+    ScheduleRead => "schedule.read",
+    ScheduleUpdate => "schedule.update",
+    ScheduleDelete => "schedule.delete",
+    SettingsRename => "settings.rename",
+    SettingsReinstall => "settings.reinstall",
+    ActivityRead => "activity.read",
+}
+
+impl Display for Permission {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for Permission {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for Permission {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let key = String::deserialize(deserializer)?;
+
+        Ok(key.parse().unwrap_or(Permission::Unknown(key)))
+    }
+}
+
+/// The error returned when parsing an unknown [`Permission`] key.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParsePermissionError(pub String);
+
+impl Display for ParsePermissionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "unknown permission '{}'", self.0)
+    }
+}
+
+impl Error for ParsePermissionError {}
+
+#[cfg(test)]
+mod tests {
+    use super::{ParsePermissionError, Permission};
+
+    #[test]
+    fn parses_known_permissions() {
+        assert_eq!(
+            "control.console".parse::<Permission>(),
+            Ok(Permission::ControlConsole)
+        );
+        assert_eq!(
+            "database.view_password".parse::<Permission>(),
+            Ok(Permission::DatabaseViewPassword)
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_permissions() {
+        assert_eq!(
+            "control.consol".parse::<Permission>(),
+            Err(ParsePermissionError("control.consol".to_string()))
+        );
+    }
+
+    #[test]
+    fn round_trips_all_permissions() {
+        for permission in Permission::ALL {
+            assert_eq!(permission.as_str().parse().as_ref(), Ok(permission));
+
+            let json = serde_json::to_string(permission).unwrap();
+            assert_eq!(json, format!("\"{}\"", permission));
+            assert_eq!(
+                &serde_json::from_str::<Permission>(&json).unwrap(),
+                permission
+            );
+        }
+    }
+
+    #[test]
+    fn deserializes_unknown_permissions() {
+        let permissions: Vec<Permission> =
+            serde_json::from_str(r#"["file.read", "addon.custom"]"#).unwrap();
+
+        assert_eq!(
+            permissions,
+            vec![
+                Permission::FileRead,
+                Permission::Unknown("addon.custom".to_string())
+            ]
+        );
+        assert_eq!(permissions[1].as_str(), "addon.custom");
+        assert!("addon.custom".parse::<Permission>().is_err());
+    }
+}
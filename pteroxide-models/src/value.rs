@@ -1,28 +1,350 @@
-use serde::{Deserialize, Serialize};
+use serde::{
+    de::{MapAccess, SeqAccess, Visitor},
+    ser::{SerializeMap, SerializeSeq},
+    Deserialize, Deserializer, Serialize,
+};
+use serde_json::{Number, Value as JsonValue};
+use std::{
+    collections::HashMap,
+    fmt::{Formatter, Result as FmtResult},
+};
 
 /// A value wrapper for environment variables. Environment objects can contain or require values of
 /// more than one type, however, Rust does not support unions so instead of coercing types from
-/// a string, this enum wraps the value to [`String`][Value::String] (which is a static string
-/// slice), [`Number`][Value::Number] (a 32-bit integer), [`Boolean`][Value::Boolean] for bools,
-/// and [`Null`][Value::Null] for [`None`].
-#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+/// a string, this enum wraps the value to [`String`][Value::String], [`Number`][Value::Number]
+/// (a 64-bit integer), [`Float`][Value::Float], [`Boolean`][Value::Boolean] for bools,
+/// [`Array`][Value::Array] and [`Object`][Value::Object] for nested values, and
+/// [`Null`][Value::Null] for [`None`].
+///
+/// Values can be created from Rust primitives and [`serde_json::Value`]s using [`From`]:
+///
+/// ```
+/// use pteroxide_models::Value;
+///
+/// assert_eq!(Value::from("server.jar"), Value::String("server.jar".to_string()));
+/// assert_eq!(Value::from(25565), Value::Number(25565));
+/// assert_eq!(Value::from(None::<bool>), Value::Null);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
 pub enum Value {
-    String(&'static str),
-    Number(i32),
+    String(String),
+    Number(i64),
+    Float(f64),
     Boolean(bool),
+    Array(Vec<Value>),
+    Object(HashMap<String, Value>),
     Null,
 }
 
+impl Value {
+    /// Returns the string slice if the value is a [`String`][Value::String].
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::String(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    /// Returns the integer if the value is a [`Number`][Value::Number].
+    pub fn as_i64(&self) -> Option<i64> {
+        match self {
+            Self::Number(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    /// Returns the value as a float if it is a [`Float`][Value::Float] or a
+    /// [`Number`][Value::Number].
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            Self::Float(v) => Some(*v),
+            Self::Number(v) => Some(*v as f64),
+            _ => None,
+        }
+    }
+
+    /// Returns the bool if the value is a [`Boolean`][Value::Boolean].
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            Self::Boolean(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if the value is [`Null`][Value::Null].
+    pub fn is_null(&self) -> bool {
+        matches!(self, Self::Null)
+    }
+}
+
 impl Serialize for Value {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
-        match *self {
+        match self {
             Self::String(v) => serializer.serialize_str(v),
-            Self::Number(v) => serializer.serialize_i32(v),
-            Self::Boolean(v) => serializer.serialize_bool(v),
+            Self::Number(v) => serializer.serialize_i64(*v),
+            Self::Float(v) => serializer.serialize_f64(*v),
+            Self::Boolean(v) => serializer.serialize_bool(*v),
+            Self::Array(v) => {
+                let mut seq = serializer.serialize_seq(Some(v.len()))?;
+                for e in v {
+                    seq.serialize_element(e)?;
+                }
+                seq.end()
+            }
+            Self::Object(v) => {
+                let mut map = serializer.serialize_map(Some(v.len()))?;
+                for (k, e) in v {
+                    map.serialize_entry(k, e)?;
+                }
+                map.end()
+            }
             Self::Null => serializer.serialize_none(),
         }
     }
 }
+
+#[doc(hidden)]
+struct ValueVisitor;
+
+impl<'de> Visitor<'de> for ValueVisitor {
+    type Value = Value;
+
+    fn expecting(&self, formatter: &mut Formatter) -> FmtResult {
+        formatter.write_str("an environment variable value")
+    }
+
+    fn visit_bool<E>(self, v: bool) -> Result<Self::Value, E> {
+        Ok(Value::Boolean(v))
+    }
+
+    fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E> {
+        Ok(Value::Number(v))
+    }
+
+    fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E> {
+        Ok(match i64::try_from(v) {
+            Ok(v) => Value::Number(v),
+            Err(_) => Value::Float(v as f64),
+        })
+    }
+
+    fn visit_f64<E>(self, v: f64) -> Result<Self::Value, E> {
+        Ok(Value::Float(v))
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> {
+        Ok(Value::String(v.to_string()))
+    }
+
+    fn visit_string<E>(self, v: String) -> Result<Self::Value, E> {
+        Ok(Value::String(v))
+    }
+
+    fn visit_none<E>(self) -> Result<Self::Value, E> {
+        Ok(Value::Null)
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Value::deserialize(deserializer)
+    }
+
+    fn visit_unit<E>(self) -> Result<Self::Value, E> {
+        Ok(Value::Null)
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut vec = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+        while let Some(e) = seq.next_element()? {
+            vec.push(e);
+        }
+
+        Ok(Value::Array(vec))
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut obj = HashMap::with_capacity(map.size_hint().unwrap_or(0));
+        while let Some((k, v)) = map.next_entry()? {
+            obj.insert(k, v);
+        }
+
+        Ok(Value::Object(obj))
+    }
+}
+
+impl<'de> Deserialize<'de> for Value {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(ValueVisitor)
+    }
+}
+
+impl From<JsonValue> for Value {
+    fn from(value: JsonValue) -> Self {
+        match value {
+            JsonValue::Null => Self::Null,
+            JsonValue::Bool(v) => Self::Boolean(v),
+            JsonValue::Number(v) => match v.as_i64() {
+                Some(i) => Self::Number(i),
+                None => Self::Float(v.as_f64().unwrap_or_default()),
+            },
+            JsonValue::String(v) => Self::String(v),
+            JsonValue::Array(v) => Self::Array(v.into_iter().map(Into::into).collect()),
+            JsonValue::Object(v) => {
+                Self::Object(v.into_iter().map(|(k, v)| (k, v.into())).collect())
+            }
+        }
+    }
+}
+
+impl From<Value> for JsonValue {
+    /// Converts the value into a [`serde_json::Value`]. Note that [`Float`][Value::Float] values
+    /// that are NaN or infinite are converted to [`Null`][JsonValue::Null] as JSON cannot
+    /// represent them.
+    fn from(value: Value) -> Self {
+        match value {
+            Value::String(v) => Self::String(v),
+            Value::Number(v) => Self::Number(v.into()),
+            Value::Float(v) => Number::from_f64(v).map_or(Self::Null, Self::Number),
+            Value::Boolean(v) => Self::Bool(v),
+            Value::Array(v) => Self::Array(v.into_iter().map(Into::into).collect()),
+            Value::Object(v) => Self::Object(v.into_iter().map(|(k, v)| (k, v.into())).collect()),
+            Value::Null => Self::Null,
+        }
+    }
+}
+
+impl From<&str> for Value {
+    fn from(value: &str) -> Self {
+        Self::String(value.to_string())
+    }
+}
+
+impl From<String> for Value {
+    fn from(value: String) -> Self {
+        Self::String(value)
+    }
+}
+
+impl From<i32> for Value {
+    fn from(value: i32) -> Self {
+        Self::Number(value.into())
+    }
+}
+
+impl From<i64> for Value {
+    fn from(value: i64) -> Self {
+        Self::Number(value)
+    }
+}
+
+impl From<f64> for Value {
+    fn from(value: f64) -> Self {
+        Self::Float(value)
+    }
+}
+
+impl From<bool> for Value {
+    fn from(value: bool) -> Self {
+        Self::Boolean(value)
+    }
+}
+
+impl From<Vec<Value>> for Value {
+    fn from(value: Vec<Value>) -> Self {
+        Self::Array(value)
+    }
+}
+
+impl From<HashMap<String, Value>> for Value {
+    fn from(value: HashMap<String, Value>) -> Self {
+        Self::Object(value)
+    }
+}
+
+impl<T> From<Option<T>> for Value
+where
+    T: Into<Value>,
+{
+    fn from(value: Option<T>) -> Self {
+        match value {
+            Some(v) => v.into(),
+            None => Self::Null,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use std::collections::HashMap;
+
+    use super::Value;
+
+    #[test]
+    fn deserializes_all_types() {
+        let value: Value = serde_json::from_str(
+            r#"{"jar": "server.jar", "port": -1, "ratio": 0.5, "eula": true,
+                "args": ["a", 2], "none": null}"#,
+        )
+        .unwrap();
+
+        let expected = Value::Object(HashMap::from([
+            ("jar".to_string(), Value::String("server.jar".to_string())),
+            ("port".to_string(), Value::Number(-1)),
+            ("ratio".to_string(), Value::Float(0.5)),
+            ("eula".to_string(), Value::Boolean(true)),
+            (
+                "args".to_string(),
+                Value::Array(vec![Value::String("a".to_string()), Value::Number(2)]),
+            ),
+            ("none".to_string(), Value::Null),
+        ]));
+        assert_eq!(value, expected);
+    }
+
+    #[test]
+    fn deserializes_large_integers_as_floats() {
+        let value: Value = serde_json::from_str(&i64::MAX.to_string()).unwrap();
+        assert_eq!(value, Value::Number(i64::MAX));
+
+        let value: Value = serde_json::from_str(&u64::MAX.to_string()).unwrap();
+        assert_eq!(value, Value::Float(u64::MAX as f64));
+    }
+
+    #[test]
+    fn round_trips_json_values() {
+        let json = json!({
+            "jar": "server.jar",
+            "port": 25565,
+            "ratio": 0.5,
+            "eula": false,
+            "args": ["-Xmx1G", null],
+        });
+
+        let value = Value::from(json.clone());
+        assert_eq!(serde_json::to_value(&value).unwrap(), json);
+        assert_eq!(serde_json::Value::from(value), json);
+    }
+
+    #[test]
+    fn converts_nan_to_null() {
+        let value = Value::from(f64::NAN);
+
+        assert_ne!(value, value.clone());
+        assert_eq!(serde_json::Value::from(value), serde_json::Value::Null);
+    }
+}
@@ -0,0 +1,4 @@
+pub mod server;
+pub mod system;
+
+pub use self::{server::*, system::*};
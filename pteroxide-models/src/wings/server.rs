@@ -0,0 +1,94 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+use crate::PowerState;
+
+/// Represents the network usage of a server on a node.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct NetworkStats {
+    pub rx_bytes: i64,
+    pub tx_bytes: i64,
+}
+
+/// Represents the resource usage of a server on a node.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct ResourceUsage {
+    pub memory_bytes: i64,
+    pub memory_limit_bytes: i64,
+    pub cpu_absolute: f64,
+    pub network: NetworkStats,
+    pub state: PowerState,
+    /// The time the server has been running for, in milliseconds.
+    pub uptime: i64,
+    pub disk_bytes: i64,
+}
+
+/// Represents the name and description of a server on a node.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct ServerMeta {
+    pub name: String,
+    pub description: String,
+}
+
+/// Represents the IP address and port of an allocation.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct DefaultAllocation {
+    pub ip: String,
+    pub port: i32,
+}
+
+/// Represents the allocations of a server on a node.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct ServerAllocations {
+    #[serde(default)]
+    pub force_outgoing_ip: bool,
+    pub default: DefaultAllocation,
+    /// A map of the IP addresses to the ports assigned to the server.
+    pub mappings: HashMap<String, Vec<i32>>,
+}
+
+/// Represents the build limits of a server on a node.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct ServerBuild {
+    pub memory_limit: i64,
+    pub swap: i64,
+    pub io_weight: i32,
+    pub cpu_limit: i64,
+    pub threads: Option<String>,
+    pub disk_space: i64,
+    pub oom_disabled: bool,
+}
+
+/// Represents the container configuration of a server on a node.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct ServerContainer {
+    pub image: String,
+    #[serde(default)]
+    pub requires_rebuild: bool,
+}
+
+/// Represents the configuration of a server that Wings received from the panel.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct ServerConfiguration {
+    pub uuid: String,
+    pub meta: ServerMeta,
+    pub suspended: bool,
+    /// The startup command with the variables replaced by their values.
+    pub invocation: String,
+    pub skip_egg_scripts: bool,
+    pub environment: HashMap<String, Value>,
+    pub allocations: ServerAllocations,
+    pub build: ServerBuild,
+    pub container: ServerContainer,
+}
+
+/// Represents a server object from the Wings API, containing the current state of the server on
+/// the node along with its configuration.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Server {
+    pub state: PowerState,
+    pub is_suspended: bool,
+    pub utilization: ResourceUsage,
+    pub configuration: ServerConfiguration,
+}
@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+/// Represents the system information of a node, as reported by Wings.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct SystemInformation {
+    pub architecture: String,
+    pub cpu_count: i32,
+    pub kernel_version: String,
+    pub os: String,
+    /// The version of Wings running on the node.
+    pub version: String,
+}